@@ -1,12 +1,18 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, LegacyConfig, Signature,
-	SudoConfig, SystemConfig, WASM_BINARY,
+	assets_registry::traits::{Asset, AssetMetadata},
+	AccountId, AssetsRegistryConfig, AuraConfig, Balance, BalancesConfig, CurrencyId,
+	GenesisConfig, GrandpaConfig, LegacyConfig, Signature, SudoConfig, SystemConfig,
+	EXISTENTIAL_DEPOSIT, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{sr25519, Pair, Public};
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_runtime::{
+	traits::{IdentifyAccount, Verify},
+	BoundedVec,
+};
+use xcm::v3::{Junction::GeneralIndex, Junctions::X1, MultiLocation};
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -124,6 +130,59 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	))
 }
 
+fn asset(
+	asset_id: CurrencyId,
+	name: &str,
+	symbol: &str,
+	decimals: u8,
+	existential_deposit: Balance,
+	location: MultiLocation,
+) -> Asset<CurrencyId, Balance> {
+	Asset {
+		asset_id,
+		decimals,
+		name: BoundedVec::truncate_from(name.as_bytes().to_vec()),
+		symbol: BoundedVec::truncate_from(symbol.as_bytes().to_vec()),
+		existential_deposit,
+		location: Some(location.into()),
+		metadata: AssetMetadata::default(),
+	}
+}
+
+/// Native token, relay chain token and a local test asset, with DOT paying for XCM execution.
+fn assets_registry_genesis() -> AssetsRegistryConfig {
+	AssetsRegistryConfig {
+		assets: vec![
+			asset(
+				CurrencyId::Native,
+				"Mech",
+				"MECH",
+				12,
+				EXISTENTIAL_DEPOSIT,
+				MultiLocation::here(),
+			),
+			asset(
+				CurrencyId::ForeignAsset(0),
+				"Polkadot",
+				"DOT",
+				10,
+				10_000_000,
+				MultiLocation::parent(),
+			),
+			asset(
+				CurrencyId::LocalAsset(1),
+				"Test",
+				"TEST",
+				12,
+				EXISTENTIAL_DEPOSIT,
+				MultiLocation::new(0, X1(GeneralIndex(1))),
+			),
+		],
+		units_per_second: vec![(CurrencyId::ForeignAsset(0), 1_000_000_000)],
+		next_asset_index: 2,
+	}
+}
+
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
 	wasm_binary: &[u8],
@@ -160,5 +219,7 @@ fn testnet_genesis(
 		parachain_info: Default::default(),
 		parachain_system: Default::default(),
 		xcm_pallet: Default::default(),
+		assets_registry: assets_registry_genesis(),
+		xcm_governance: Default::default(),
 	}
 }
@@ -0,0 +1,66 @@
+use crate::{
+	pallet::{AssetStatuses, Config, Error, ExistentialDeposits, Pallet},
+	traits::AssetStatus,
+};
+use frame_support::pallet_prelude::{DispatchResult, PhantomData};
+use orml_traits::{
+	currency::{OnDeposit, OnTransfer},
+	GetByKey,
+};
+use sp_runtime::traits::Zero;
+use xcm::v3::MultiLocation;
+
+/// Existential deposits taken from the registry, meant to be used as `orml_tokens`
+/// `ExistentialDeposits` so that the deposits set by governance are the ones enforced.
+///
+/// Assets that are not registered have no existential deposit.
+pub struct RegistryExistentialDeposits<T>(PhantomData<T>);
+impl<T: Config> GetByKey<T::CurrencyId, T::Balance> for RegistryExistentialDeposits<T> {
+	fn get(currency_id: &T::CurrencyId) -> T::Balance {
+		ExistentialDeposits::<T>::get(currency_id).unwrap_or_else(Zero::zero)
+	}
+}
+
+/// Minimum XCM fees taken from the registry, meant to be used as `orml_xtokens` `MinXcmFee`.
+///
+/// Fees are looked up by the location of the reserve `orml_xtokens` gives.
+pub struct RegistryMinXcmFees<T>(PhantomData<T>);
+impl<T: Config> GetByKey<MultiLocation, Option<T::Balance>> for RegistryMinXcmFees<T> {
+	fn get(location: &MultiLocation) -> Option<T::Balance> {
+		Pallet::<T>::min_xcm_fee(*location)
+	}
+}
+
+/// `orml_tokens` hooks enforcing the lifecycle state of assets: frozen assets cannot be
+/// transferred, and deprecated ones can neither be transferred nor deposited.
+pub struct AssetStatusFilter<T>(PhantomData<T>);
+impl<T: Config, AccountId> OnTransfer<AccountId, T::CurrencyId, T::Balance>
+	for AssetStatusFilter<T>
+{
+	fn on_transfer(
+		currency_id: T::CurrencyId,
+		_from: &AccountId,
+		_to: &AccountId,
+		_amount: T::Balance,
+	) -> DispatchResult {
+		match AssetStatuses::<T>::get(currency_id) {
+			AssetStatus::Active => Ok(()),
+			AssetStatus::Frozen => Err(Error::<T>::AssetFrozen.into()),
+			AssetStatus::Deprecated => Err(Error::<T>::AssetDeprecated.into()),
+		}
+	}
+}
+impl<T: Config, AccountId> OnDeposit<AccountId, T::CurrencyId, T::Balance>
+	for AssetStatusFilter<T>
+{
+	fn on_deposit(
+		currency_id: T::CurrencyId,
+		_who: &AccountId,
+		_amount: T::Balance,
+	) -> DispatchResult {
+		match AssetStatuses::<T>::get(currency_id) {
+			AssetStatus::Deprecated => Err(Error::<T>::AssetDeprecated.into()),
+			AssetStatus::Active | AssetStatus::Frozen => Ok(()),
+		}
+	}
+}
@@ -0,0 +1,147 @@
+//! Benchmarking setup for assets-registry
+
+use super::*;
+
+use crate::traits::{AssetDetails, AssetMetadata, AssetStatus, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH};
+#[allow(unused)]
+use crate::Pallet as AssetsRegistry;
+use frame_benchmarking::{
+	v1::{benchmarks, whitelisted_caller},
+	BenchmarkError,
+};
+use frame_support::{
+	sp_std::boxed::Box,
+	traits::{Currency, EnsureOrigin, ReservableCurrency},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Zero};
+use xcm::v3::{Junction::Parachain, Junctions::X1, MultiLocation};
+
+fn sibling(para_id: u32) -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(para_id)))
+}
+
+/// Asset with the longest name and symbol allowed.
+fn asset_details<T: Config>(location: MultiLocation) -> AssetDetails<T::Balance> {
+	AssetDetails {
+		decimals: 12,
+		name: BoundedVec::truncate_from([b'a'; MAX_NAME_LENGTH as usize].to_vec()),
+		symbol: BoundedVec::truncate_from([b'A'; MAX_SYMBOL_LENGTH as usize].to_vec()),
+		existential_deposit: Zero::zero(),
+		location: Some(location.into()),
+		metadata: AssetMetadata::default(),
+	}
+}
+
+fn register<T: Config>(location: MultiLocation) -> T::CurrencyId {
+	AssetsRegistry::<T>::do_register_new_asset(asset_details::<T>(location))
+		.expect("asset is valid and the location is free")
+}
+
+fn propose<T: Config>(location: MultiLocation) -> T::AccountId {
+	let proposer: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&proposer, DepositBalanceOf::<T>::max_value() / 2u32.into());
+	AssetsRegistry::<T>::do_propose_asset(proposer.clone(), asset_details::<T>(location))
+		.expect("proposer can afford the deposit");
+	proposer
+}
+
+benchmarks! {
+	register_asset {
+		let origin =
+			T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset = asset_details::<T>(sibling(2000));
+	}: _<T::RuntimeOrigin>(origin, asset)
+	verify {
+		assert!(AssetsRegistry::<T>::get_asset_id_by_location(sibling(2000)).is_some());
+	}
+
+	update_asset {
+		let origin =
+			T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset_id = register::<T>(sibling(2000));
+		// Moving the asset to another location is the heaviest update.
+		let asset = asset_details::<T>(sibling(3000));
+	}: _<T::RuntimeOrigin>(origin, asset_id, asset)
+	verify {
+		assert_eq!(AssetsRegistry::<T>::get_location_by_asset(asset_id), Some(sibling(3000)));
+	}
+
+	delete_asset {
+		let origin =
+			T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset_id = register::<T>(sibling(2000));
+		AssetsRegistry::<T>::do_set_units_per_second(asset_id, Some(1)).map_err(|e| e.error)?;
+	}: _<T::RuntimeOrigin>(origin, asset_id)
+	verify {
+		assert!(!AssetsRegistry::<T>::is_asset_registered(&asset_id));
+	}
+
+	propose_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value() / 2u32.into());
+		let asset = asset_details::<T>(sibling(2000));
+	}: _(RawOrigin::Signed(caller), asset)
+	verify {
+		assert!(AssetProposals::<T>::contains_key(0));
+	}
+
+	approve_asset_proposal {
+		let origin =
+			T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let proposer = propose::<T>(sibling(2000));
+	}: _<T::RuntimeOrigin>(origin, 0)
+	verify {
+		assert!(AssetsRegistry::<T>::get_asset_id_by_location(sibling(2000)).is_some());
+		assert!(T::Currency::reserved_balance(&proposer).is_zero());
+	}
+
+	reject_asset_proposal {
+		let origin =
+			T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let proposer = propose::<T>(sibling(2000));
+	}: _<T::RuntimeOrigin>(origin, 0)
+	verify {
+		assert!(!AssetProposals::<T>::contains_key(0));
+		assert!(T::Currency::reserved_balance(&proposer).is_zero());
+	}
+
+	set_units_per_second {
+		let origin =
+			T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset_id = register::<T>(sibling(2000));
+	}: _<T::RuntimeOrigin>(origin, asset_id, Some(1_000_000))
+	verify {
+		assert_eq!(AssetsRegistry::<T>::get_units_per_second(asset_id), Some(1_000_000));
+	}
+
+	set_min_xcm_fee {
+		let origin =
+			T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, Box::new(sibling(2000).into()), Zero::zero())
+	verify {
+		assert!(AssetsRegistry::<T>::min_xcm_fee(sibling(2000)).is_some());
+	}
+
+	remove_min_xcm_fee {
+		let origin =
+			T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		AssetsRegistry::<T>::do_set_min_xcm_fee(sibling(2000).into(), Zero::zero())
+			.map_err(|e| e.error)?;
+	}: _<T::RuntimeOrigin>(origin, Box::new(sibling(2000).into()))
+	verify {
+		assert!(AssetsRegistry::<T>::min_xcm_fee(sibling(2000)).is_none());
+	}
+
+	set_asset_status {
+		let origin =
+			T::RegistryOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset_id = register::<T>(sibling(2000));
+	}: _<T::RuntimeOrigin>(origin, asset_id, AssetStatus::Frozen)
+	verify {
+		assert_eq!(AssetsRegistry::<T>::get_asset_status(asset_id), Some(AssetStatus::Frozen));
+	}
+
+	impl_benchmark_test_suite!(AssetsRegistry, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -2,16 +2,55 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod adapters;
+pub mod migrations;
+pub mod trader;
 pub mod traits;
+pub mod weights;
+pub use weights::*;
 
-#[frame_support::pallet(dev_mode)]
+#[frame_support::pallet]
 pub mod pallet {
-	use crate::traits::{Asset, AssetRegistryReader};
-	use frame_support::{inherent::Vec, pallet_prelude::*, traits::tokens::Balance, Twox64Concat};
-	use frame_system::{ensure_root, pallet_prelude::OriginFor};
-	use xcm::v3::MultiLocation;
+	use super::weights::WeightInfo;
+	use crate::traits::{
+		AllocateAssetId, Asset, AssetDetails, AssetField, AssetLocation, AssetProposal,
+		AssetRegistryReader, AssetStatus, AssetsPage, MAX_DECIMALS,
+	};
+	use frame_support::{
+		inherent::Vec,
+		pallet_prelude::*,
+		sp_std::boxed::Box,
+		storage::StoragePrefixedMap,
+		traits::{tokens::Balance, Currency, OnUnbalanced, ReservableCurrency},
+		Twox64Concat,
+	};
+	use frame_system::{
+		ensure_signed,
+		pallet_prelude::{BlockNumberFor, OriginFor},
+	};
+	use orml_traits::GetByKey;
+	use sp_runtime::traits::Zero;
+	use xcm::{latest::MultiLocation, VersionedMultiLocation};
+
+	/// Maximum number of assets returned in a single page.
+	pub const MAX_ASSETS_PAGE_SIZE: u32 = 100;
+	/// Number of assets returned in a page when no limit is given.
+	pub const DEFAULT_ASSETS_PAGE_SIZE: u32 = 50;
+
+	/// The current storage version.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -22,9 +61,72 @@ pub mod pallet {
 	pub(super) type ExistentialDeposits<T: Config> =
 		StorageMap<_, Twox64Concat, T::CurrencyId, T::Balance, OptionQuery>;
 
+	/// Lifecycle state of every registered asset, `Active` unless set otherwise.
+	#[pallet::storage]
+	pub(super) type AssetStatuses<T: Config> =
+		StorageMap<_, Twox64Concat, T::CurrencyId, AssetStatus, ValueQuery>;
+
+	/// Units of an asset charged per second of XCM execution weight, for assets that can be used
+	/// to pay for XCM execution.
+	#[pallet::storage]
+	pub(super) type UnitsPerSecond<T: Config> =
+		StorageMap<_, Twox64Concat, T::CurrencyId, u128, OptionQuery>;
+
+	/// Minimum fee charged for transfers whose fees are reserved at a location, by the location
+	/// of the reserve as given by `orml_xtokens`, in the current XCM version.
+	#[pallet::storage]
+	pub(super) type MinXcmFees<T: Config> =
+		StorageMap<_, Twox64Concat, AssetLocation, T::Balance, OptionQuery>;
+
+	/// Registered asset at each location, locations being keyed in the current XCM version.
 	#[pallet::storage]
 	pub(super) type LocationToAssetId<T: Config> =
-		StorageMap<_, Twox64Concat, MultiLocation, T::CurrencyId, OptionQuery>;
+		StorageMap<_, Twox64Concat, AssetLocation, T::CurrencyId, OptionQuery>;
+
+	/// Index used to allocate the id of the next registered asset.
+	#[pallet::storage]
+	pub(super) type NextAssetIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	pub(super) type ProposalCount<T: Config> = StorageValue<_, ProposalIndex, ValueQuery>;
+
+	/// Asset registrations proposed by regular accounts, waiting to be approved or rejected.
+	#[pallet::storage]
+	pub(super) type AssetProposals<T: Config> =
+		StorageMap<_, Twox64Concat, ProposalIndex, AssetProposalOf<T>, OptionQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Assets registered at genesis, under the ids they carry.
+		pub assets: Vec<Asset<T::CurrencyId, T::Balance>>,
+		/// XCM execution rates of genesis assets.
+		pub units_per_second: Vec<(T::CurrencyId, u128)>,
+		/// First index allocated to assets registered after genesis. It has to be past any
+		/// index used by the ids of genesis assets.
+		pub next_asset_index: u32,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { assets: Vec::new(), units_per_second: Vec::new(), next_asset_index: 0 }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for asset in &self.assets {
+				Pallet::<T>::do_register_asset(asset.asset_id, asset.clone().into())
+					.expect("genesis assets are valid and unique; qed");
+			}
+			for (asset_id, units_per_second) in &self.units_per_second {
+				Pallet::<T>::do_set_units_per_second(*asset_id, Some(*units_per_second))
+					.expect("execution rates are only set for genesis assets; qed");
+			}
+			NextAssetIndex::<T>::put(self.next_asset_index);
+		}
+	}
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -32,17 +134,71 @@ pub mod pallet {
 		AssetAlreadyRegistered,
 		/// Asset does not exist.
 		AssetDoesNotExist,
+		/// Asset proposal does not exist.
+		ProposalDoesNotExist,
+		/// Asset name is empty or not valid UTF-8.
+		InvalidAssetName,
+		/// Asset symbol is empty or not valid UTF-8.
+		InvalidAssetSymbol,
+		/// Asset has more decimals than allowed.
+		InvalidAssetDecimals,
+		/// Asset extra metadata is not valid UTF-8.
+		InvalidAssetMetadata,
+		/// Another asset is already registered at the given location.
+		LocationAlreadyRegistered,
+		/// All asset indices have been used.
+		NoAvailableAssetId,
+		/// No minimum fee is set for the given parachain and location.
+		MinXcmFeeDoesNotExist,
+		/// Asset still has issuance, so it cannot be deleted.
+		AssetStillInUse,
+		/// Asset is frozen, so it cannot be transferred.
+		AssetFrozen,
+		/// Asset is deprecated, so it can only be withdrawn.
+		AssetDeprecated,
+		/// Location cannot be represented in the current XCM version.
+		UnsupportedLocationVersion,
+		/// All proposal indices have been used.
+		NoAvailableProposalIndex,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// New asset has been registered.
-		AssetRegistered(T::CurrencyId),
-		/// Asset has been updated.
-		AssetUpdated(T::CurrencyId),
-		/// Asset has been deleted.
-		AssetDeleted(T::CurrencyId),
+		AssetRegistered { asset_id: T::CurrencyId, asset: AssetDetails<T::Balance> },
+		/// Asset has been updated, `changed` listing the fields that differ between `old` and
+		/// `new`.
+		AssetUpdated {
+			asset_id: T::CurrencyId,
+			old: AssetDetails<T::Balance>,
+			new: AssetDetails<T::Balance>,
+			changed: Vec<AssetField>,
+		},
+		/// Asset has been deleted, `asset` being its data at the time.
+		AssetDeleted { asset_id: T::CurrencyId, asset: AssetDetails<T::Balance> },
+		/// New asset has been proposed.
+		AssetProposed(ProposalIndex, T::AccountId),
+		/// Asset proposal has been approved and the asset registered.
+		AssetProposalApproved(ProposalIndex, T::CurrencyId),
+		/// Asset proposal has been rejected and its deposit slashed.
+		AssetProposalRejected(ProposalIndex),
+		/// XCM execution rate of an asset has been set, or removed if `None`.
+		UnitsPerSecondChanged(T::CurrencyId, Option<u128>),
+		/// Lifecycle state of an asset has changed.
+		AssetStatusChanged(T::CurrencyId, AssetStatus),
+		/// Minimum fee for transfers with fees reserved at a location has been set.
+		MinXcmFeeSet(VersionedMultiLocation, T::Balance),
+		/// Minimum fee for transfers with fees reserved at a location has been removed.
+		MinXcmFeeRemoved(VersionedMultiLocation),
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::config]
@@ -55,37 +211,139 @@ pub mod pallet {
 			+ MaybeSerializeDeserialize
 			+ Ord
 			+ Clone
-			+ Default;
+			+ Default
+			+ MaxEncodedLen;
 
 		type Balance: Balance;
+
+		/// Total issuance of every asset, so that assets still in use are not deleted.
+		type AssetIssuance: GetByKey<Self::CurrencyId, Self::Balance>;
+
+		/// Builds the ids of newly registered assets.
+		type AssetIdAllocator: AllocateAssetId<Self::CurrencyId>;
+
+		/// Origin allowed to register, update and delete assets, and to approve or reject
+		/// asset proposals.
+		type RegistryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Currency in which asset proposal deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Deposit reserved when proposing a new asset.
+		#[pallet::constant]
+		type ProposalDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// Handler for the deposits slashed from rejected proposals.
+		type RejectedProposalSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		type WeightInfo: WeightInfo;
 	}
 
+	pub type ProposalIndex = u32;
+
+	/// Id of a parachain.
+	pub type ParaId = u32;
+
+	type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+
+	pub type DepositBalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+
+	pub type NegativeImbalanceOf<T> =
+		<<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
+
+	type AssetProposalOf<T> =
+		AssetProposal<AccountIdOf<T>, DepositBalanceOf<T>, <T as Config>::Balance>;
+
 	// Pallet internal functions
 	impl<T: Config> Pallet<T> {
-		pub fn get_value() -> Option<u32> {
-			Some(123_u32)
-		}
-
 		pub fn get_asset_by_location(
-			location: MultiLocation,
+			location: impl Into<VersionedMultiLocation>,
 		) -> Option<Asset<T::CurrencyId, T::Balance>> {
-			let asset_id = LocationToAssetId::<T>::get(location)?;
+			let asset_id = Self::get_asset_id_by_location(location)?;
 			AssetsMap::<T>::get(asset_id)
 		}
 
-		pub fn get_asset_id_by_location(location: MultiLocation) -> Option<T::CurrencyId> {
+		/// Id of the asset at `location`, which can be given in any supported XCM version.
+		pub fn get_asset_id_by_location(
+			location: impl Into<VersionedMultiLocation>,
+		) -> Option<T::CurrencyId> {
+			let location = Self::normalize_location(location.into()).ok()?;
 			LocationToAssetId::<T>::get(location)
 		}
 
+		/// Location of the asset in the current XCM version.
 		pub fn get_location_by_asset(asset_id: T::CurrencyId) -> Option<MultiLocation> {
-			if let Some(loc) = AssetsMap::<T>::get(asset_id).map(|asset| asset.location) {
-				return loc
-			}
-			return None
+			let location = AssetsMap::<T>::get(asset_id)?.location?;
+			MultiLocation::try_from(location).ok()
+		}
+
+		/// Converts `location` to the current XCM version, the one locations are stored in.
+		pub fn normalize_location(
+			location: VersionedMultiLocation,
+		) -> Result<AssetLocation, Error<T>> {
+			MultiLocation::try_from(location)
+				.map(Into::into)
+				.map_err(|_| Error::<T>::UnsupportedLocationVersion)
+		}
+
+		/// Converts the location of `asset` to the current XCM version.
+		fn normalize_asset(
+			mut asset: AssetDetails<T::Balance>,
+		) -> Result<AssetDetails<T::Balance>, Error<T>> {
+			asset.location = asset
+				.location
+				.map(|location| Self::normalize_location(location.0))
+				.transpose()?;
+			Ok(asset)
+		}
+
+		/// Prefix of the storage keys of the registered assets, for clients watching the registry
+		/// for changes.
+		pub fn assets_storage_prefix() -> [u8; 32] {
+			AssetsMap::<T>::final_prefix()
+		}
+
+		/// Returns up to `limit` registered assets, starting right after `cursor`. A `limit` of
+		/// zero stands for the default page size.
+		///
+		/// Assets are returned in storage order, so the `next_cursor` of a page has to be passed
+		/// back in order to get the following one.
+		pub fn get_assets(
+			cursor: Option<T::CurrencyId>,
+			limit: u32,
+		) -> AssetsPage<T::CurrencyId, T::Balance> {
+			let limit = match limit {
+				0 => DEFAULT_ASSETS_PAGE_SIZE,
+				limit => limit.min(MAX_ASSETS_PAGE_SIZE),
+			} as usize;
+			let mut iter = match cursor {
+				Some(asset_id) =>
+					AssetsMap::<T>::iter_from(AssetsMap::<T>::hashed_key_for(asset_id)),
+				None => AssetsMap::<T>::iter(),
+			};
+			let assets: Vec<_> = iter.by_ref().take(limit).map(|(_, asset)| asset).collect();
+			let next_cursor = match iter.next() {
+				Some(_) => assets.last().map(|asset| asset.asset_id),
+				None => None,
+			};
+
+			AssetsPage { assets, next_cursor }
+		}
+
+		/// Units of the asset charged per second of XCM execution, if it can pay for it.
+		pub fn get_units_per_second(asset_id: T::CurrencyId) -> Option<u128> {
+			UnitsPerSecond::<T>::get(asset_id)
 		}
 
-		pub fn get_assets_names() -> Vec<Vec<u8>> {
-			AssetsMap::<T>::iter().map(|(_, asset)| asset.name).collect()
+		/// Minimum fee for transfers with fees reserved at `location`.
+		pub fn min_xcm_fee(location: impl Into<VersionedMultiLocation>) -> Option<T::Balance> {
+			let location = Self::normalize_location(location.into()).ok()?;
+			MinXcmFees::<T>::get(location)
+		}
+
+		/// Lifecycle state of the asset, `None` if it is not registered.
+		pub fn get_asset_status(asset_id: T::CurrencyId) -> Option<AssetStatus> {
+			Self::is_asset_registered(&asset_id).then(|| AssetStatuses::<T>::get(asset_id))
 		}
 
 		/// Checks if asset is registered.
@@ -93,55 +351,268 @@ pub mod pallet {
 			AssetsMap::<T>::contains_key(asset_id)
 		}
 
-		/// Registers asset.
+		/// Checks that the asset metadata is well formed.
+		pub fn ensure_valid_asset(asset: &AssetDetails<T::Balance>) -> DispatchResult {
+			ensure!(Self::is_valid_text(&asset.name), Error::<T>::InvalidAssetName);
+			ensure!(Self::is_valid_text(&asset.symbol), Error::<T>::InvalidAssetSymbol);
+			ensure!(asset.decimals <= MAX_DECIMALS, Error::<T>::InvalidAssetDecimals);
+			if let Some(coingecko_id) = &asset.metadata.coingecko_id {
+				ensure!(Self::is_valid_text(coingecko_id), Error::<T>::InvalidAssetMetadata);
+			}
+			Ok(())
+		}
+
+		fn is_valid_text(text: &[u8]) -> bool {
+			!text.is_empty() && core::str::from_utf8(text).is_ok()
+		}
+
+		/// Checks that no asset is registered at `location`, so that assets and locations stay
+		/// in a one to one relation.
+		fn ensure_location_available(location: &Option<AssetLocation>) -> DispatchResult {
+			if let Some(location) = location {
+				ensure!(
+					!LocationToAssetId::<T>::contains_key(location),
+					Error::<T>::LocationAlreadyRegistered
+				);
+			}
+			Ok(())
+		}
+
+		/// Allocates a new asset id for an asset at `location`.
+		fn next_asset_id(location: Option<&MultiLocation>) -> Result<T::CurrencyId, DispatchError> {
+			let index = NextAssetIndex::<T>::get();
+			let next_index = index.checked_add(1).ok_or(Error::<T>::NoAvailableAssetId)?;
+			NextAssetIndex::<T>::put(next_index);
+			Ok(T::AssetIdAllocator::allocate(index, location))
+		}
+
+		/// Registers asset under a newly allocated id.
+		pub fn do_register_new_asset(
+			details: AssetDetails<T::Balance>,
+		) -> Result<T::CurrencyId, DispatchError> {
+			let details = Self::normalize_asset(details)?;
+			let location = details.location.clone().and_then(|l| MultiLocation::try_from(l).ok());
+			let asset_id = Self::next_asset_id(location.as_ref())?;
+			Self::do_register_asset(asset_id, details)?;
+			Ok(asset_id)
+		}
+
+		/// Registers asset under the given id.
 		pub fn do_register_asset(
-			asset: Asset<T::CurrencyId, T::Balance>,
+			asset_id: T::CurrencyId,
+			asset: AssetDetails<T::Balance>,
 		) -> DispatchResultWithPostInfo {
-			let asset_id = asset.asset_id;
 			ensure!(!Self::is_asset_registered(&asset_id), Error::<T>::AssetAlreadyRegistered);
+			let asset = Self::normalize_asset(asset)?;
+			Self::ensure_valid_asset(&asset)?;
+			Self::ensure_location_available(&asset.location)?;
 			ExistentialDeposits::<T>::insert(asset_id, &asset.existential_deposit);
-			AssetsMap::<T>::insert(asset_id, asset.clone());
-			if let Some(location) = asset.location {
+			if let Some(location) = &asset.location {
 				LocationToAssetId::<T>::insert(location, asset_id);
 			}
+			AssetsMap::<T>::insert(asset_id, Asset::new(asset_id, asset.clone()));
 
-			Self::deposit_event(Event::AssetRegistered(asset_id));
+			Self::deposit_event(Event::AssetRegistered { asset_id, asset });
 
 			Ok(().into())
 		}
 
 		/// Update asset.
 		pub fn do_update_asset(
-			asset: Asset<T::CurrencyId, T::Balance>,
+			asset_id: T::CurrencyId,
+			asset: AssetDetails<T::Balance>,
 		) -> DispatchResultWithPostInfo {
-			let asset_id = asset.asset_id;
-			ensure!(Self::is_asset_registered(&asset_id), Error::<T>::AssetDoesNotExist);
-			ExistentialDeposits::<T>::insert(asset_id, &asset.existential_deposit);
-			let old_asset =
-				AssetsMap::<T>::get(asset_id).expect("Asset must exist for previous check");
-			AssetsMap::<T>::insert(asset_id, asset.clone());
-			if let Some(location) = asset.clone().location {
-				if let Some(old_location) = old_asset.location {
+			let old_asset = AssetsMap::<T>::get(asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
+			let asset = Self::normalize_asset(asset)?;
+			Self::ensure_valid_asset(&asset)?;
+			if asset.location != old_asset.location {
+				Self::ensure_location_available(&asset.location)?;
+				if let Some(old_location) = &old_asset.location {
 					LocationToAssetId::<T>::remove(old_location);
 				}
-				LocationToAssetId::<T>::insert(location, asset_id);
+				if let Some(location) = &asset.location {
+					LocationToAssetId::<T>::insert(location, asset_id);
+				}
 			}
+			ExistentialDeposits::<T>::insert(asset_id, &asset.existential_deposit);
+			AssetsMap::<T>::insert(asset_id, Asset::new(asset_id, asset.clone()));
 
-			Self::deposit_event(Event::AssetUpdated(asset_id));
+			let old_asset = AssetDetails::from(old_asset);
+			let changed = old_asset.changed_fields(&asset);
+			Self::deposit_event(Event::AssetUpdated {
+				asset_id,
+				old: old_asset,
+				new: asset,
+				changed,
+			});
 
 			Ok(().into())
 		}
 
-		/// Delete asset.
+		/// Delete asset, as long as none of it is issued.
 		pub fn do_delete_asset(asset_id: T::CurrencyId) -> DispatchResultWithPostInfo {
 			ensure!(Self::is_asset_registered(&asset_id), Error::<T>::AssetDoesNotExist);
+			ensure!(T::AssetIssuance::get(&asset_id).is_zero(), Error::<T>::AssetStillInUse);
+			let asset = AssetsMap::<T>::take(asset_id).ok_or(Error::<T>::AssetDoesNotExist)?;
 			ExistentialDeposits::<T>::remove(asset_id);
-			AssetsMap::<T>::remove(asset_id);
+			AssetStatuses::<T>::remove(asset_id);
+			UnitsPerSecond::<T>::remove(asset_id);
+			if let Some(location) = &asset.location {
+				LocationToAssetId::<T>::remove(location);
+			}
+
+			Self::deposit_event(Event::AssetDeleted { asset_id, asset: asset.into() });
+
+			Ok(().into())
+		}
+
+		/// Sets the lifecycle state of an asset.
+		pub fn do_set_asset_status(
+			asset_id: T::CurrencyId,
+			status: AssetStatus,
+		) -> DispatchResultWithPostInfo {
+			ensure!(Self::is_asset_registered(&asset_id), Error::<T>::AssetDoesNotExist);
+			AssetStatuses::<T>::insert(asset_id, status);
+
+			Self::deposit_event(Event::AssetStatusChanged(asset_id, status));
 
-			Self::deposit_event(Event::AssetDeleted(asset_id));
+			Ok(().into())
+		}
+
+		/// Sets the XCM execution rate of an asset, or removes it if `None`.
+		pub fn do_set_units_per_second(
+			asset_id: T::CurrencyId,
+			units_per_second: Option<u128>,
+		) -> DispatchResultWithPostInfo {
+			ensure!(Self::is_asset_registered(&asset_id), Error::<T>::AssetDoesNotExist);
+			UnitsPerSecond::<T>::set(asset_id, units_per_second);
+
+			Self::deposit_event(Event::UnitsPerSecondChanged(asset_id, units_per_second));
+
+			Ok(().into())
+		}
+
+		/// Sets the minimum fee for transfers with fees reserved at `location`.
+		pub fn do_set_min_xcm_fee(
+			location: VersionedMultiLocation,
+			fee: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let location = Self::normalize_location(location)?;
+			MinXcmFees::<T>::insert(&location, fee);
+
+			Self::deposit_event(Event::MinXcmFeeSet(location.into(), fee));
 
 			Ok(().into())
 		}
+
+		/// Removes the minimum fee for transfers with fees reserved at `location`.
+		pub fn do_remove_min_xcm_fee(
+			location: VersionedMultiLocation,
+		) -> DispatchResultWithPostInfo {
+			let location = Self::normalize_location(location)?;
+			MinXcmFees::<T>::take(&location).ok_or(Error::<T>::MinXcmFeeDoesNotExist)?;
+
+			Self::deposit_event(Event::MinXcmFeeRemoved(location.into()));
+
+			Ok(().into())
+		}
+
+		/// Stores a new asset proposal, reserving the deposit from the proposer.
+		pub fn do_propose_asset(
+			proposer: T::AccountId,
+			asset: AssetDetails<T::Balance>,
+		) -> DispatchResultWithPostInfo {
+			let asset = Self::normalize_asset(asset)?;
+			Self::ensure_valid_asset(&asset)?;
+			Self::ensure_location_available(&asset.location)?;
+			let index = ProposalCount::<T>::get();
+			let next_index = index.checked_add(1).ok_or(Error::<T>::NoAvailableProposalIndex)?;
+			let deposit = T::ProposalDeposit::get();
+			T::Currency::reserve(&proposer, deposit)?;
+
+			ProposalCount::<T>::put(next_index);
+			AssetProposals::<T>::insert(
+				index,
+				AssetProposal { proposer: proposer.clone(), deposit, asset },
+			);
+
+			Self::deposit_event(Event::AssetProposed(index, proposer));
+
+			Ok(().into())
+		}
+
+		/// Registers the proposed asset and gives the deposit back to the proposer.
+		pub fn do_approve_asset_proposal(index: ProposalIndex) -> DispatchResultWithPostInfo {
+			let proposal =
+				AssetProposals::<T>::take(index).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			let asset_id = Self::do_register_new_asset(proposal.asset)?;
+			T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+
+			Self::deposit_event(Event::AssetProposalApproved(index, asset_id));
+
+			Ok(().into())
+		}
+
+		/// Drops the proposal and slashes its deposit.
+		pub fn do_reject_asset_proposal(index: ProposalIndex) -> DispatchResultWithPostInfo {
+			let proposal =
+				AssetProposals::<T>::take(index).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			let (imbalance, _) = T::Currency::slash_reserved(&proposal.proposer, proposal.deposit);
+			T::RejectedProposalSlash::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::AssetProposalRejected(index));
+
+			Ok(().into())
+		}
+
+		/// Checks that every asset location is indexed, and that the index only points to
+		/// registered assets at that same location. All locations have to be in the current
+		/// XCM version.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			let is_normalized = |location: &AssetLocation| {
+				Self::normalize_location(location.0.clone()).ok().as_ref() == Some(location)
+			};
+			for (asset_id, asset) in AssetsMap::<T>::iter() {
+				ensure!(
+					ExistentialDeposits::<T>::get(asset_id) == Some(asset.existential_deposit),
+					"asset existential deposit out of sync"
+				);
+				if let Some(location) = asset.location {
+					ensure!(is_normalized(&location), "asset location in an outdated XCM version");
+					ensure!(
+						LocationToAssetId::<T>::get(location) == Some(asset_id),
+						"asset location is not indexed"
+					);
+				}
+			}
+			for (location, asset_id) in LocationToAssetId::<T>::iter() {
+				ensure!(
+					AssetsMap::<T>::get(asset_id).and_then(|asset| asset.location) ==
+						Some(location),
+					"location index points to an asset at a different location"
+				);
+			}
+			for location in MinXcmFees::<T>::iter_keys() {
+				ensure!(is_normalized(&location), "min fee location in an outdated XCM version");
+			}
+			for asset_id in ExistentialDeposits::<T>::iter_keys() {
+				ensure!(
+					Self::is_asset_registered(&asset_id),
+					"existential deposit of an unregistered asset"
+				);
+			}
+			for asset_id in AssetStatuses::<T>::iter_keys() {
+				ensure!(Self::is_asset_registered(&asset_id), "status of an unregistered asset");
+			}
+			for asset_id in UnitsPerSecond::<T>::iter_keys() {
+				ensure!(
+					Self::is_asset_registered(&asset_id),
+					"execution rate of an unregistered asset"
+				);
+			}
+			Ok(())
+		}
 	}
 
 	impl<T: Config> AssetRegistryReader<T::CurrencyId, T::Balance> for Pallet<T> {
@@ -150,7 +621,11 @@ pub mod pallet {
 		}
 
 		fn get_asset_name(asset_id: T::CurrencyId) -> Option<Vec<u8>> {
-			AssetsMap::<T>::get(asset_id).map(|asset| asset.name)
+			AssetsMap::<T>::get(asset_id).map(|asset| asset.name.into_inner())
+		}
+
+		fn get_asset_symbol(asset_id: T::CurrencyId) -> Option<Vec<u8>> {
+			AssetsMap::<T>::get(asset_id).map(|asset| asset.symbol.into_inner())
 		}
 
 		fn get_asset_decimals(asset_id: T::CurrencyId) -> Option<u8> {
@@ -160,39 +635,133 @@ pub mod pallet {
 		fn get_asset_existential_deposit(asset_id: T::CurrencyId) -> Option<T::Balance> {
 			ExistentialDeposits::<T>::get(asset_id)
 		}
+
+		fn get_asset_id_by_location(location: &MultiLocation) -> Option<T::CurrencyId> {
+			Pallet::<T>::get_asset_id_by_location(*location)
+		}
+
+		fn get_location_by_asset(asset_id: T::CurrencyId) -> Option<MultiLocation> {
+			Pallet::<T>::get_location_by_asset(asset_id)
+		}
+
+		fn is_asset_registered(asset_id: &T::CurrencyId) -> bool {
+			Pallet::<T>::is_asset_registered(asset_id)
+		}
+
+		fn get_assets(
+			cursor: Option<T::CurrencyId>,
+			limit: u32,
+		) -> AssetsPage<T::CurrencyId, T::Balance> {
+			Pallet::<T>::get_assets(cursor, limit)
+		}
 	}
 
 	// Pallet extrinsics
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_asset())]
 		#[pallet::call_index(0)]
 		pub fn register_asset(
 			origin: OriginFor<T>,
-			asset: Asset<T::CurrencyId, T::Balance>,
+			asset: AssetDetails<T::Balance>,
 		) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
-			Self::do_register_asset(asset)
+			T::RegistryOrigin::ensure_origin(origin)?;
+			Self::do_register_new_asset(asset)?;
+			Ok(().into())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_asset())]
 		#[pallet::call_index(1)]
 		pub fn update_asset(
 			origin: OriginFor<T>,
-			asset: Asset<T::CurrencyId, T::Balance>,
+			asset_id: T::CurrencyId,
+			asset: AssetDetails<T::Balance>,
 		) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
-			Self::do_update_asset(asset)
+			T::RegistryOrigin::ensure_origin(origin)?;
+			Self::do_update_asset(asset_id, asset)
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::delete_asset())]
 		#[pallet::call_index(2)]
 		pub fn delete_asset(
 			origin: OriginFor<T>,
 			asset_id: T::CurrencyId,
 		) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+			T::RegistryOrigin::ensure_origin(origin)?;
 			Self::do_delete_asset(asset_id)
 		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::propose_asset())]
+		#[pallet::call_index(3)]
+		pub fn propose_asset(
+			origin: OriginFor<T>,
+			asset: AssetDetails<T::Balance>,
+		) -> DispatchResultWithPostInfo {
+			let proposer = ensure_signed(origin)?;
+			Self::do_propose_asset(proposer, asset)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::approve_asset_proposal())]
+		#[pallet::call_index(4)]
+		pub fn approve_asset_proposal(
+			origin: OriginFor<T>,
+			index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			Self::do_approve_asset_proposal(index)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::reject_asset_proposal())]
+		#[pallet::call_index(5)]
+		pub fn reject_asset_proposal(
+			origin: OriginFor<T>,
+			index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			Self::do_reject_asset_proposal(index)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::set_units_per_second())]
+		#[pallet::call_index(6)]
+		pub fn set_units_per_second(
+			origin: OriginFor<T>,
+			asset_id: T::CurrencyId,
+			units_per_second: Option<u128>,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			Self::do_set_units_per_second(asset_id, units_per_second)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::set_min_xcm_fee())]
+		#[pallet::call_index(7)]
+		pub fn set_min_xcm_fee(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			fee: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			Self::do_set_min_xcm_fee(*location, fee)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::remove_min_xcm_fee())]
+		#[pallet::call_index(8)]
+		pub fn remove_min_xcm_fee(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			Self::do_remove_min_xcm_fee(*location)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_status())]
+		#[pallet::call_index(9)]
+		pub fn set_asset_status(
+			origin: OriginFor<T>,
+			asset_id: T::CurrencyId,
+			status: AssetStatus,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+			Self::do_set_asset_status(asset_id, status)
+		}
 	}
 }
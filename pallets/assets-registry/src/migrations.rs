@@ -0,0 +1,561 @@
+use crate::{
+	pallet::{
+		AssetProposals, AssetsMap, Config, DepositBalanceOf, ExistentialDeposits,
+		LocationToAssetId, MinXcmFees, NextAssetIndex, Pallet, ParaId, ProposalIndex,
+	},
+	traits::{self, AssetLocation, AssetMetadata, AssetName, AssetSymbol},
+};
+use frame_support::{
+	inherent::Vec,
+	pallet_prelude::*,
+	storage::{
+		migration::{put_storage_value, storage_iter, storage_key_iter},
+		storage_prefix, PrefixIterator,
+	},
+	traits::{GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess},
+};
+use sp_runtime::traits::Convert;
+use xcm::{v3::MultiLocation, VersionedMultiLocation};
+
+fn pallet_prefix<T: Config>() -> &'static [u8] {
+	<Pallet<T> as PalletInfoAccess>::name().as_bytes()
+}
+
+/// Storage layout up to v2, when locations were stored as v3 `MultiLocation`s.
+///
+/// Kept apart from the pallet types so that earlier migrations keep reading and writing the
+/// layout they were written for.
+pub(crate) mod unversioned {
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	pub struct Asset<RegisteredAssetId, Balance> {
+		pub asset_id: RegisteredAssetId,
+		pub decimals: u8,
+		pub name: AssetName,
+		pub symbol: AssetSymbol,
+		pub existential_deposit: Balance,
+		pub location: Option<MultiLocation>,
+		pub metadata: AssetMetadata,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct AssetDetails<Balance> {
+		pub decimals: u8,
+		pub name: AssetName,
+		pub symbol: AssetSymbol,
+		pub existential_deposit: Balance,
+		pub location: Option<MultiLocation>,
+		pub metadata: AssetMetadata,
+	}
+
+	/// Asset proposal, carrying an `Asset` up to v1 and `AssetDetails` in v2.
+	#[derive(Encode, Decode)]
+	pub struct AssetProposal<AccountId, Deposit, ProposedAsset> {
+		pub proposer: AccountId,
+		pub deposit: Deposit,
+		pub asset: ProposedAsset,
+	}
+
+	impl<RegisteredAssetId, Balance> Asset<RegisteredAssetId, Balance> {
+		pub fn with_id<NewAssetId>(self, asset_id: NewAssetId) -> Asset<NewAssetId, Balance> {
+			Asset {
+				asset_id,
+				decimals: self.decimals,
+				name: self.name,
+				symbol: self.symbol,
+				existential_deposit: self.existential_deposit,
+				location: self.location,
+				metadata: self.metadata,
+			}
+		}
+	}
+
+	impl<RegisteredAssetId, Balance> From<Asset<RegisteredAssetId, Balance>> for AssetDetails<Balance> {
+		fn from(asset: Asset<RegisteredAssetId, Balance>) -> Self {
+			AssetDetails {
+				decimals: asset.decimals,
+				name: asset.name,
+				symbol: asset.symbol,
+				existential_deposit: asset.existential_deposit,
+				location: asset.location,
+				metadata: asset.metadata,
+			}
+		}
+	}
+
+	impl<RegisteredAssetId, Balance> From<Asset<RegisteredAssetId, Balance>>
+		for traits::Asset<RegisteredAssetId, Balance>
+	{
+		fn from(asset: Asset<RegisteredAssetId, Balance>) -> Self {
+			traits::Asset {
+				asset_id: asset.asset_id,
+				decimals: asset.decimals,
+				name: asset.name,
+				symbol: asset.symbol,
+				existential_deposit: asset.existential_deposit,
+				location: asset.location.map(Into::into),
+				metadata: asset.metadata,
+			}
+		}
+	}
+
+	impl<Balance> From<AssetDetails<Balance>> for traits::AssetDetails<Balance> {
+		fn from(asset: AssetDetails<Balance>) -> Self {
+			traits::AssetDetails {
+				decimals: asset.decimals,
+				name: asset.name,
+				symbol: asset.symbol,
+				existential_deposit: asset.existential_deposit,
+				location: asset.location.map(Into::into),
+				metadata: asset.metadata,
+			}
+		}
+	}
+
+	pub type AssetProposalOf<T, ProposedAsset> =
+		AssetProposal<<T as frame_system::Config>::AccountId, DepositBalanceOf<T>, ProposedAsset>;
+
+	#[frame_support::storage_alias]
+	pub type AssetsMap<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as Config>::CurrencyId,
+		Asset<<T as Config>::CurrencyId, <T as Config>::Balance>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type LocationToAssetId<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, MultiLocation, <T as Config>::CurrencyId>;
+
+	#[frame_support::storage_alias]
+	pub type MinXcmFees<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		ParaId,
+		Twox64Concat,
+		MultiLocation,
+		<T as Config>::Balance,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type AssetProposals<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		ProposalIndex,
+		AssetProposalOf<T, AssetDetails<<T as Config>::Balance>>,
+	>;
+}
+
+/// Migrates assets from an unbounded name to bounded name and symbol, plus extra metadata.
+///
+/// Entries are rewritten in place without decoding their keys, so the migration can run on
+/// chains whose stored asset ids are not yet of the `CurrencyId` type configured in the
+/// runtime: `StoredCurrencyId` is the type asset ids are stored with at this point.
+pub mod v1 {
+	use super::{unversioned::Asset, *};
+
+	#[derive(Decode)]
+	struct OldAsset<RegisteredAssetId, Balance> {
+		asset_id: RegisteredAssetId,
+		decimals: u8,
+		name: Vec<u8>,
+		existential_deposit: Balance,
+		location: Option<MultiLocation>,
+	}
+
+	impl<RegisteredAssetId, Balance> From<OldAsset<RegisteredAssetId, Balance>>
+		for Asset<RegisteredAssetId, Balance>
+	{
+		fn from(old: OldAsset<RegisteredAssetId, Balance>) -> Self {
+			// Names longer than the new bound get truncated, and since there was no symbol until
+			// now it starts off as the (truncated) name.
+			Asset {
+				asset_id: old.asset_id,
+				decimals: old.decimals,
+				name: truncate_text(&old.name),
+				symbol: truncate_text(&old.name),
+				existential_deposit: old.existential_deposit,
+				location: old.location,
+				metadata: AssetMetadata::default(),
+			}
+		}
+	}
+
+	/// Keeps as much of `text` as fits in `Bound` bytes without splitting a character, so that
+	/// truncated names stay valid UTF-8. Bytes past the first invalid UTF-8 sequence are dropped.
+	fn truncate_text<Bound: Get<u32>>(text: &[u8]) -> BoundedVec<u8, Bound> {
+		let valid = core::str::from_utf8(text).unwrap_or_else(|err| {
+			log::warn!(
+				target: "runtime::assets-registry",
+				"asset name {:?} is not valid UTF-8, keeping its first {} bytes",
+				text,
+				err.valid_up_to()
+			);
+			core::str::from_utf8(&text[..err.valid_up_to()]).unwrap_or_default()
+		});
+		let mut end = valid.len().min(Bound::get() as usize);
+		while !valid.is_char_boundary(end) {
+			end -= 1;
+		}
+		BoundedVec::truncate_from(valid.as_bytes()[..end].to_vec())
+	}
+
+	/// Splits the raw `AssetsMap` entries into the assets that decode with the v0 layout and the
+	/// keys of those that do not, so that the latter are reported rather than silently dropped.
+	fn old_assets<StoredCurrencyId: Decode, Balance: Decode>(
+		prefix: &[u8],
+	) -> (Vec<(Vec<u8>, OldAsset<StoredCurrencyId, Balance>)>, Vec<Vec<u8>>) {
+		let map_prefix = storage_prefix(prefix, b"AssetsMap").to_vec();
+		let mut assets = Vec::new();
+		let mut undecodable = Vec::new();
+		for (key, value) in PrefixIterator::<(Vec<u8>, Vec<u8>)>::new(
+			map_prefix.clone(),
+			map_prefix,
+			|key, value| Ok((key.to_vec(), value.to_vec())),
+		) {
+			match OldAsset::decode(&mut &value[..]) {
+				Ok(asset) => assets.push((key, asset)),
+				Err(_) => undecodable.push(key),
+			}
+		}
+		(assets, undecodable)
+	}
+
+	/// Asset proposal as stored by v1, still carrying the asset id.
+	pub(crate) type V1AssetProposal<AccountId, Deposit, RegisteredAssetId, Balance> =
+		unversioned::AssetProposal<AccountId, Deposit, Asset<RegisteredAssetId, Balance>>;
+
+	pub struct MigrateToV1<T: Config, StoredCurrencyId = <T as Config>::CurrencyId>(
+		PhantomData<(T, StoredCurrencyId)>,
+	);
+	impl<T: Config, StoredCurrencyId: Encode + Decode> OnRuntimeUpgrade
+		for MigrateToV1<T, StoredCurrencyId>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 0 {
+				log::info!(
+					target: "runtime::assets-registry",
+					"skipping v1 migration, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let prefix = pallet_prefix::<T>();
+			let (assets, undecodable) = old_assets::<StoredCurrencyId, T::Balance>(prefix);
+			for key in &undecodable {
+				log::error!(
+					target: "runtime::assets-registry",
+					"asset stored under {:?} cannot be decoded, leaving it as is",
+					key
+				);
+			}
+			let read = (assets.len() + undecodable.len()) as u64;
+			let translated = assets.len() as u64;
+
+			for (key, old) in assets {
+				put_storage_value(
+					prefix,
+					b"AssetsMap",
+					&key,
+					Asset::<StoredCurrencyId, T::Balance>::from(old),
+				);
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(
+				target: "runtime::assets-registry",
+				"migrated {} assets to v1, {} could not be decoded",
+				translated,
+				undecodable.len()
+			);
+
+			T::DbWeight::get().reads_writes(read + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let (assets, undecodable) =
+				old_assets::<StoredCurrencyId, T::Balance>(pallet_prefix::<T>());
+			ensure!(undecodable.is_empty(), "some assets cannot be decoded and would be lost");
+			Ok((assets.len() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let assets: u32 =
+				Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			let prefix = pallet_prefix::<T>();
+			ensure!(
+				storage_iter::<Asset<StoredCurrencyId, T::Balance>>(prefix, b"AssetsMap").count()
+					as u32 == assets,
+				"assets were lost in the migration"
+			);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+			Ok(())
+		}
+	}
+}
+
+/// Moves assets from the ids they were stored with to the runtime `CurrencyId`, and drops the
+/// asset id from pending proposals, since ids are now allocated on registration.
+///
+/// `ConvertId` maps every stored id to its new value, and `FirstFreeIndex` is the first index
+/// the registry can allocate without clashing with the converted ids.
+pub mod v2 {
+	use super::{
+		unversioned::{Asset, AssetDetails, AssetProposal},
+		v1::V1AssetProposal,
+		*,
+	};
+
+	pub struct MigrateToV2<T, OldCurrencyId, ConvertId, FirstFreeIndex>(
+		PhantomData<(T, OldCurrencyId, ConvertId, FirstFreeIndex)>,
+	);
+	impl<T, OldCurrencyId, ConvertId, FirstFreeIndex> OnRuntimeUpgrade
+		for MigrateToV2<T, OldCurrencyId, ConvertId, FirstFreeIndex>
+	where
+		T: Config,
+		OldCurrencyId: Decode,
+		ConvertId: Convert<OldCurrencyId, T::CurrencyId>,
+		FirstFreeIndex: Get<u32>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 1 {
+				log::info!(
+					target: "runtime::assets-registry",
+					"skipping v2 migration, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let prefix = pallet_prefix::<T>();
+			// Old and new ids may share their encoding, so every entry is taken out before any
+			// of them is inserted back.
+			let assets: Vec<_> = storage_key_iter::<
+				OldCurrencyId,
+				Asset<OldCurrencyId, T::Balance>,
+				Twox64Concat,
+			>(prefix, b"AssetsMap")
+			.drain()
+			.collect();
+			let existential_deposits: Vec<_> = storage_key_iter::<
+				OldCurrencyId,
+				T::Balance,
+				Twox64Concat,
+			>(prefix, b"ExistentialDeposits")
+			.drain()
+			.collect();
+			let mut migrated = (assets.len() + existential_deposits.len()) as u64;
+
+			for (old_id, asset) in assets {
+				let asset_id = ConvertId::convert(old_id);
+				unversioned::AssetsMap::<T>::insert(asset_id, asset.with_id(asset_id));
+			}
+			for (old_id, existential_deposit) in existential_deposits {
+				ExistentialDeposits::<T>::insert(ConvertId::convert(old_id), existential_deposit);
+			}
+			unversioned::LocationToAssetId::<T>::translate::<OldCurrencyId, _>(|_, old_id| {
+				migrated += 1;
+				Some(ConvertId::convert(old_id))
+			});
+			unversioned::AssetProposals::<T>::translate::<
+				V1AssetProposal<T::AccountId, DepositBalanceOf<T>, OldCurrencyId, T::Balance>,
+				_,
+			>(|_, old| {
+				migrated += 1;
+				Some(AssetProposal {
+					proposer: old.proposer,
+					deposit: old.deposit,
+					asset: AssetDetails::from(old.asset),
+				})
+			});
+			NextAssetIndex::<T>::put(FirstFreeIndex::get());
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::assets-registry", "migrated {} entries to v2", migrated);
+
+			T::DbWeight::get().reads_writes(migrated + 1, migrated + 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let prefix = pallet_prefix::<T>();
+			let assets = storage_iter::<Asset<OldCurrencyId, T::Balance>>(prefix, b"AssetsMap")
+				.count() as u32;
+			let proposals = unversioned::AssetProposals::<T>::iter_keys().count() as u32;
+			Ok((assets, proposals).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (assets, proposals): (u32, u32) =
+				Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				unversioned::AssetsMap::<T>::iter_values().count() as u32 == assets,
+				"assets were lost in the migration"
+			);
+			ensure!(
+				unversioned::AssetProposals::<T>::iter_values().count() as u32 == proposals,
+				"asset proposals were lost in the migration"
+			);
+			for (location, asset_id) in unversioned::LocationToAssetId::<T>::iter() {
+				ensure!(
+					unversioned::AssetsMap::<T>::get(asset_id).and_then(|asset| asset.location) ==
+						Some(location),
+					"location index points to an asset at a different location"
+				);
+			}
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "storage version not updated");
+			Ok(())
+		}
+	}
+}
+
+/// Stores locations as `VersionedMultiLocation`s, so that they survive XCM version upgrades.
+pub mod v3 {
+	use super::*;
+
+	pub struct MigrateToV3<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 2 {
+				log::info!(
+					target: "runtime::assets-registry",
+					"skipping v3 migration, on-chain storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut migrated = 0u64;
+			AssetsMap::<T>::translate::<unversioned::Asset<T::CurrencyId, T::Balance>, _>(
+				|_, old| {
+					migrated += 1;
+					Some(old.into())
+				},
+			);
+			AssetProposals::<T>::translate::<
+				unversioned::AssetProposalOf<T, unversioned::AssetDetails<T::Balance>>,
+				_,
+			>(|_, old| {
+				migrated += 1;
+				Some(traits::AssetProposal {
+					proposer: old.proposer,
+					deposit: old.deposit,
+					asset: old.asset.into(),
+				})
+			});
+			// Keys change their encoding, so entries are taken out and inserted back under the
+			// versioned location.
+			let locations: Vec<_> = unversioned::LocationToAssetId::<T>::drain().collect();
+			let min_xcm_fees: Vec<_> = unversioned::MinXcmFees::<T>::drain().collect();
+			migrated += (locations.len() + min_xcm_fees.len()) as u64;
+			for (location, asset_id) in locations {
+				LocationToAssetId::<T>::insert(VersionedMultiLocation::from(location), asset_id);
+			}
+			// The reserve location already gives the parachain the fee was keyed by.
+			for (_, location, fee) in min_xcm_fees {
+				MinXcmFees::<T>::insert(VersionedMultiLocation::from(location), fee);
+			}
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::assets-registry", "migrated {} entries to v3", migrated);
+
+			T::DbWeight::get().reads_writes(migrated + 1, migrated * 2 + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let locations = unversioned::LocationToAssetId::<T>::iter_keys().count() as u32;
+			// Fees are only kept by reserve location from now on.
+			let min_xcm_fees = unversioned::MinXcmFees::<T>::iter_keys()
+				.map(|(_, location)| location)
+				.collect::<frame_support::sp_std::collections::btree_set::BTreeSet<_>>()
+				.len() as u32;
+			Ok((locations, min_xcm_fees).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (locations, min_xcm_fees): (u32, u32) =
+				Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+			ensure!(
+				LocationToAssetId::<T>::iter_keys().count() as u32 == locations,
+				"asset locations were lost in the migration"
+			);
+			ensure!(
+				MinXcmFees::<T>::iter_keys().count() as u32 == min_xcm_fees,
+				"min xcm fees were lost in the migration"
+			);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 3, "storage version not updated");
+			Pallet::<T>::do_try_state()
+		}
+	}
+
+	/// Converts stored locations to the current XCM version.
+	///
+	/// Not versioned, as it is only needed when the runtime moves to a new XCM version: it has
+	/// to be added to the runtime migrations along with that upgrade. Locations that cannot be
+	/// represented in the new version are left untouched.
+	pub struct NormalizeLocations<T>(PhantomData<T>);
+	impl<T: Config> NormalizeLocations<T> {
+		fn normalize(location: AssetLocation) -> AssetLocation {
+			Pallet::<T>::normalize_location(location.0.clone()).unwrap_or_else(|_| {
+				log::warn!(
+					target: "runtime::assets-registry",
+					"location {:?} cannot be represented in the current XCM version",
+					location
+				);
+				location
+			})
+		}
+	}
+	impl<T: Config> OnRuntimeUpgrade for NormalizeLocations<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut migrated = 0u64;
+			AssetsMap::<T>::translate::<traits::Asset<T::CurrencyId, T::Balance>, _>(
+				|_, mut asset| {
+					migrated += 1;
+					asset.location = asset.location.map(Self::normalize);
+					Some(asset)
+				},
+			);
+			AssetProposals::<T>::translate::<
+				traits::AssetProposal<T::AccountId, DepositBalanceOf<T>, T::Balance>,
+				_,
+			>(|_, mut proposal| {
+				migrated += 1;
+				proposal.asset.location = proposal.asset.location.map(Self::normalize);
+				Some(proposal)
+			});
+			let locations: Vec<_> = LocationToAssetId::<T>::drain().collect();
+			let min_xcm_fees: Vec<_> = MinXcmFees::<T>::drain().collect();
+			migrated += (locations.len() + min_xcm_fees.len()) as u64;
+			for (location, asset_id) in locations {
+				LocationToAssetId::<T>::insert(Self::normalize(location), asset_id);
+			}
+			for (location, fee) in min_xcm_fees {
+				MinXcmFees::<T>::insert(Self::normalize(location), fee);
+			}
+
+			log::info!(
+				target: "runtime::assets-registry",
+				"normalized the locations of {} entries",
+				migrated
+			);
+
+			T::DbWeight::get().reads_writes(migrated, migrated * 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
@@ -0,0 +1,130 @@
+use crate as assets_registry;
+use assets_registry::traits::AllocateAssetId;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use orml_traits::GetByKey;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use xcm::v3::{MultiAsset, MultiLocation};
+use xcm_builder::TakeRevenue;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type CurrencyId = u32;
+pub type Balance = u64;
+
+pub const ALICE: u64 = 1;
+pub const ALICE_INITIAL_BALANCE: Balance = 1_000;
+pub const PROPOSAL_DEPOSIT: Balance = 100;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		AssetsRegistry: assets_registry,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type ExistentialDeposit = ConstU64<1>;
+}
+
+/// Uses the allocation index itself as asset id.
+pub struct IndexAsAssetId;
+impl AllocateAssetId<CurrencyId> for IndexAsAssetId {
+	fn allocate(index: u32, _location: Option<&MultiLocation>) -> CurrencyId {
+		index
+	}
+}
+
+parameter_types! {
+	pub static CollectedRevenue: Vec<MultiAsset> = vec![];
+	pub static Issuance: Balance = 0;
+}
+
+/// Every asset has the issuance set in `Issuance`.
+pub struct MockIssuance;
+impl GetByKey<CurrencyId, Balance> for MockIssuance {
+	fn get(_currency_id: &CurrencyId) -> Balance {
+		Issuance::get()
+	}
+}
+
+/// Keeps track of the XCM fees charged by the trader.
+pub struct RevenueCollector;
+impl TakeRevenue for RevenueCollector {
+	fn take_revenue(revenue: MultiAsset) {
+		CollectedRevenue::mutate(|collected| collected.push(revenue));
+	}
+}
+
+impl assets_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CurrencyId = CurrencyId;
+	type Balance = Balance;
+	type AssetIssuance = MockIssuance;
+	type AssetIdAllocator = IndexAsAssetId;
+	type RegistryOrigin = EnsureRoot<u64>;
+	type Currency = Balances;
+	type ProposalDeposit = ConstU64<PROPOSAL_DEPOSIT>;
+	type RejectedProposalSlash = ();
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(ALICE, ALICE_INITIAL_BALANCE)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -1,12 +1,46 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use assets_registry::traits::{Asset, AssetStatus, AssetsPage};
+use codec::Codec;
 use frame_support::inherent::Vec;
+use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
 sp_api::decl_runtime_apis! {
-	#[api_version(2)]
-	pub trait AssetsRegistryApi {
+	/// Queries over the assets registry.
+	///
+	/// Version history:
+	/// - 3: `get_assets`, `get_asset` and `get_asset_by_location` are added, `get_value` and
+	///   `get_assets_names` are dropped.
+	/// - 4: `get_asset_status` is added.
+	/// - 5: `get_asset_by_location` takes a versioned location.
+	#[api_version(5)]
+	pub trait AssetsRegistryApi<CurrencyId, Balance>
+	where
+		CurrencyId: Codec,
+		Balance: Codec,
+	{
+		/// Returns a page of at most `limit` assets, starting right after `cursor`.
+		fn get_assets(cursor: Option<CurrencyId>, limit: u32) -> AssetsPage<CurrencyId, Balance>;
+
+		fn get_asset(asset_id: CurrencyId) -> Option<Asset<CurrencyId, Balance>>;
+
+		/// Returns the asset at `location`, which can be given in any supported XCM version.
+		fn get_asset_by_location(
+			location: VersionedMultiLocation,
+		) -> Option<Asset<CurrencyId, Balance>>;
+
+		#[changed_in(5)]
+		fn get_asset_by_location(location: MultiLocation) -> Option<Asset<CurrencyId, Balance>>;
+
+		/// Returns the lifecycle state of the asset, `None` if it is not registered.
+		fn get_asset_status(asset_id: CurrencyId) -> Option<AssetStatus>;
+
+		/// Placeholder value, removed in version 3.
+		#[changed_in(3)]
 		fn get_value() -> u32;
 
+		/// Names of every registered asset, removed in version 3 in favour of `get_assets`.
+		#[changed_in(3)]
 		fn get_assets_names() -> Vec<Vec<u8>>;
 	}
 }
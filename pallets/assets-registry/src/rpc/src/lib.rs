@@ -1,52 +1,325 @@
+use assets_registry::{
+	traits::{Asset, AssetStatus, AssetsPage},
+	DEFAULT_ASSETS_PAGE_SIZE,
+};
 pub use assets_registry_runtime_api::AssetsRegistryApi as AssetsRegistryRuntimeApi;
+use codec::{Codec, Encode};
+use futures::{future, FutureExt, StreamExt};
 use jsonrpsee::{
 	core::{Error as JsonRpseeError, RpcResult},
 	proc_macros::rpc,
-	types::error::{CallError, ErrorObject},
+	types::{
+		error::{CallError, ErrorObject},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
 };
-use sp_api::ProvideRuntimeApi;
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sc_rpc::SubscriptionTaskExecutor;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiError, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::blake2_256, storage::StorageKey, H256};
+use sp_rpc::number::NumberOrHex;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
+use xcm::{latest::MultiLocation, VersionedMultiLocation};
+
+/// Asset as exposed through RPC, with a human readable name.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcAsset<CurrencyId> {
+	pub asset_id: CurrencyId,
+	pub decimals: u8,
+	pub name: String,
+	pub symbol: String,
+	pub existential_deposit: NumberOrHex,
+	/// Location of the asset in the current XCM version.
+	pub location: Option<MultiLocation>,
+	pub coingecko_id: Option<String>,
+	pub icon_hash: Option<H256>,
+}
+
+impl<CurrencyId, Balance: Into<NumberOrHex>> From<Asset<CurrencyId, Balance>>
+	for RpcAsset<CurrencyId>
+{
+	fn from(asset: Asset<CurrencyId, Balance>) -> Self {
+		Self {
+			asset_id: asset.asset_id,
+			decimals: asset.decimals,
+			name: String::from_utf8_lossy(&asset.name).into_owned(),
+			symbol: String::from_utf8_lossy(&asset.symbol).into_owned(),
+			existential_deposit: asset.existential_deposit.into(),
+			location: asset.location.and_then(|location| location.try_into().ok()),
+			coingecko_id: asset
+				.metadata
+				.coingecko_id
+				.map(|id| String::from_utf8_lossy(&id).into_owned()),
+			icon_hash: asset.metadata.icon_hash.map(H256::from),
+		}
+	}
+}
+
+/// Page of assets as exposed through RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcAssetsPage<CurrencyId> {
+	pub assets: Vec<RpcAsset<CurrencyId>>,
+	pub next_cursor: Option<CurrencyId>,
+}
+
+impl<CurrencyId, Balance: Into<NumberOrHex>> From<AssetsPage<CurrencyId, Balance>>
+	for RpcAssetsPage<CurrencyId>
+{
+	fn from(page: AssetsPage<CurrencyId, Balance>) -> Self {
+		Self {
+			assets: page.assets.into_iter().map(Into::into).collect(),
+			next_cursor: page.next_cursor,
+		}
+	}
+}
+
+/// Lifecycle state of an asset as exposed through RPC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcAssetStatus {
+	Active,
+	Frozen,
+	Deprecated,
+}
+
+impl From<AssetStatus> for RpcAssetStatus {
+	fn from(status: AssetStatus) -> Self {
+		match status {
+			AssetStatus::Active => Self::Active,
+			AssetStatus::Frozen => Self::Frozen,
+			AssetStatus::Deprecated => Self::Deprecated,
+		}
+	}
+}
 
 #[rpc(client, server)]
-pub trait AssetsRegistryApi<BlockHash> {
-	#[method(name = "assetsRegistry_getValue")]
-	fn get_value(&self, at: Option<BlockHash>) -> RpcResult<u32>;
+pub trait AssetsRegistryApi<BlockHash, CurrencyId, Balance> {
+	#[method(name = "assetsRegistry_getAssets")]
+	fn get_assets(
+		&self,
+		cursor: Option<CurrencyId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<RpcAssetsPage<CurrencyId>>;
 
-	#[method(name = "assetsRegistry_getAssetsNames")]
-	fn get_assets_names(&self, at: Option<BlockHash>) -> RpcResult<Vec<Vec<u8>>>;
+	#[method(name = "assetsRegistry_getAsset")]
+	fn get_asset(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcAsset<CurrencyId>>>;
+
+	/// Looks the asset up by its location, given in any supported XCM version.
+	#[method(name = "assetsRegistry_getAssetByLocation")]
+	fn get_asset_by_location(
+		&self,
+		location: VersionedMultiLocation,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcAsset<CurrencyId>>>;
+
+	#[method(name = "assetsRegistry_getAssetStatus")]
+	fn get_asset_status(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcAssetStatus>>;
+
+	/// Sends every registered asset as of the last finalized block, then the full list again each
+	/// time a finalized block changes it.
+	#[subscription(
+		name = "assetsRegistry_subscribeAssets" => "assetsRegistry_assets",
+		unsubscribe = "assetsRegistry_unsubscribeAssets",
+		item = Vec<RpcAsset<CurrencyId>>,
+	)]
+	fn subscribe_assets(&self);
 }
 
-pub struct AssetsRegistryPallet<C, Block> {
+pub struct AssetsRegistryPallet<C, Block, BE> {
 	client: Arc<C>,
-	_marker: std::marker::PhantomData<Block>,
+	executor: SubscriptionTaskExecutor,
+	/// Prefix of the storage keys of the registered assets in the runtime.
+	assets_prefix: StorageKey,
+	_marker: std::marker::PhantomData<(Block, BE)>,
 }
 
-impl<C, Block> AssetsRegistryPallet<C, Block> {
-	pub fn new(client: Arc<C>) -> Self {
-		Self { client, _marker: Default::default() }
+impl<C, Block, BE> AssetsRegistryPallet<C, Block, BE> {
+	/// `assets_prefix` is the prefix of the storage keys of the registered assets, as given by
+	/// the `assets_storage_prefix` of the registry pallet in the runtime.
+	pub fn new(
+		client: Arc<C>,
+		executor: SubscriptionTaskExecutor,
+		assets_prefix: [u8; 32],
+	) -> Self {
+		Self {
+			client,
+			executor,
+			assets_prefix: StorageKey(assets_prefix.to_vec()),
+			_marker: Default::default(),
+		}
 	}
 }
 
-impl<C, Block> AssetsRegistryApiServer<<Block as BlockT>::Hash> for AssetsRegistryPallet<C, Block>
+/// Hash of the registered assets stored at block `at`, which changes whenever they do.
+fn assets_storage_hash<C, Block, BE>(
+	client: &C,
+	at: <Block as BlockT>::Hash,
+	assets_prefix: &StorageKey,
+) -> sp_blockchain::Result<[u8; 32]>
 where
 	Block: BlockT,
-	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-	C::Api: AssetsRegistryRuntimeApi<Block>,
+	BE: Backend<Block>,
+	C: StorageProvider<Block, BE>,
 {
-	fn get_value(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+	client.storage_pairs(at, assets_prefix).map(|pairs| blake2_256(&pairs.encode()))
+}
+
+/// Collects every registered asset at block `at`, going through all pages.
+fn all_assets<C, Block, CurrencyId, Balance>(
+	client: &C,
+	at: <Block as BlockT>::Hash,
+) -> Result<Vec<RpcAsset<CurrencyId>>, ApiError>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: AssetsRegistryRuntimeApi<Block, CurrencyId, Balance>,
+	CurrencyId: Codec,
+	Balance: Codec + Into<NumberOrHex>,
+{
+	let api = client.runtime_api();
+	let mut assets = Vec::new();
+	let mut cursor = None;
+	loop {
+		let page = api.get_assets(at, cursor, DEFAULT_ASSETS_PAGE_SIZE)?;
+		assets.extend(page.assets.into_iter().map(RpcAsset::from));
+		match page.next_cursor {
+			Some(next) => cursor = Some(next),
+			None => return Ok(assets),
+		}
+	}
+}
+
+impl<C, Block, BE, CurrencyId, Balance>
+	AssetsRegistryApiServer<<Block as BlockT>::Hash, CurrencyId, Balance>
+	for AssetsRegistryPallet<C, Block, BE>
+where
+	Block: BlockT,
+	BE: Backend<Block> + 'static,
+	C: Send
+		+ Sync
+		+ 'static
+		+ ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ StorageProvider<Block, BE>,
+	C::Api: AssetsRegistryRuntimeApi<Block, CurrencyId, Balance>,
+	CurrencyId: Codec + Send + Sync + 'static,
+	Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+{
+	fn get_assets(
+		&self,
+		cursor: Option<CurrencyId>,
+		limit: Option<u32>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<RpcAssetsPage<CurrencyId>> {
 		let api = self.client.runtime_api();
 		let at = at.unwrap_or_else(|| self.client.info().best_hash);
 
-		api.get_value(at).map_err(runtime_error_into_rpc_err)
+		api.get_assets(at, cursor, limit.unwrap_or(DEFAULT_ASSETS_PAGE_SIZE))
+			.map(Into::into)
+			.map_err(runtime_error_into_rpc_err)
 	}
 
-	fn get_assets_names(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<Vec<u8>>> {
+	fn get_asset(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RpcAsset<CurrencyId>>> {
 		let api = self.client.runtime_api();
 		let at = at.unwrap_or_else(|| self.client.info().best_hash);
 
-		api.get_assets_names(at).map_err(runtime_error_into_rpc_err)
+		api.get_asset(at, asset_id)
+			.map(|asset| asset.map(Into::into))
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_asset_by_location(
+		&self,
+		location: VersionedMultiLocation,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RpcAsset<CurrencyId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_asset_by_location(at, location)
+			.map(|asset| asset.map(Into::into))
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn get_asset_status(
+		&self,
+		asset_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RpcAssetStatus>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_asset_status(at, asset_id)
+			.map(|status| status.map(Into::into))
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn subscribe_assets(&self, mut sink: SubscriptionSink) -> SubscriptionResult {
+		let client = self.client.clone();
+		let assets_prefix = self.assets_prefix.clone();
+		// Finalized blocks are subscribed to before the assets are read, so that none is missed.
+		let finalized = client.finality_notification_stream();
+		let at = client.info().finalized_hash;
+		let initial = assets_storage_hash::<_, _, BE>(&*client, at, &assets_prefix)
+			.map_err(runtime_error_into_rpc_err)
+			.and_then(|hash| {
+				let assets = all_assets(&*client, at).map_err(runtime_error_into_rpc_err)?;
+				Ok((hash, assets))
+			});
+		let (mut last_hash, initial) = match initial {
+			Ok(initial) => initial,
+			Err(err) => {
+				let _ = sink.reject(err);
+				return Ok(())
+			},
+		};
+
+		// The registry is only queried again when the stored assets differ from the ones of the
+		// last finalized block they were sent for, so blocks that are not finalized, or that do
+		// not touch the registry, send nothing.
+		let changes = finalized.filter_map(move |notification| {
+			let at = notification.hash;
+			let update = match assets_storage_hash::<_, _, BE>(&*client, at, &assets_prefix) {
+				Ok(hash) if hash != last_hash => match all_assets(&*client, at) {
+					Ok(assets) => {
+						last_hash = hash;
+						Some(assets)
+					},
+					Err(_) => None,
+				},
+				_ => None,
+			};
+			future::ready(update)
+		});
+		let stream = futures::stream::iter(Some(initial)).chain(changes);
+
+		let fut = async move {
+			sink.pipe_from_stream(stream).await;
+		};
+		self.executor
+			.spawn("assets-registry-rpc-subscription", Some("rpc"), fut.boxed());
+
+		Ok(())
 	}
 }
 
@@ -0,0 +1,1018 @@
+use crate::{
+	adapters::{AssetStatusFilter, RegistryExistentialDeposits, RegistryMinXcmFees},
+	migrations::{unversioned, v1::MigrateToV1, v3::MigrateToV3},
+	mock::*,
+	pallet::{
+		AssetProposals, AssetsMap, ExistentialDeposits, LocationToAssetId, NextAssetIndex,
+		ProposalCount, UnitsPerSecond,
+	},
+	trader::RegistryWeightTrader,
+	traits::{
+		Asset, AssetDetails, AssetField, AssetLocation, AssetMetadata, AssetRegistryReader,
+		AssetStatus, InMemoryAssetRegistry, MAX_DECIMALS,
+	},
+	Error, Event,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	pallet_prelude::StorageVersion,
+	traits::{
+		GenesisBuild, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageInfoTrait,
+	},
+	weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+	BoundedVec,
+};
+use orml_traits::{
+	currency::{OnDeposit, OnTransfer},
+	GetByKey,
+};
+use xcm::{
+	v3::{prelude::*, Junction::Parachain, Junctions::X1, MultiLocation, Weight as XcmWeight},
+	VersionedMultiLocation,
+};
+use xcm_executor::{traits::WeightTrader, Assets};
+
+fn asset(location: Option<MultiLocation>) -> AssetDetails<Balance> {
+	AssetDetails {
+		decimals: 12,
+		name: BoundedVec::truncate_from(b"Test asset".to_vec()),
+		symbol: BoundedVec::truncate_from(b"TEST".to_vec()),
+		existential_deposit: 10,
+		location: location.map(Into::into),
+		metadata: AssetMetadata::default(),
+	}
+}
+
+fn sibling(para_id: u32) -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(para_id)))
+}
+
+#[test]
+fn registers_asset_location() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(MultiLocation::parent()))
+		));
+
+		assert_eq!(
+			LocationToAssetId::<Test>::get(VersionedMultiLocation::from(MultiLocation::parent())),
+			Some(0)
+		);
+		assert_eq!(AssetsRegistry::get_location_by_asset(0), Some(MultiLocation::parent()));
+		System::assert_last_event(
+			Event::AssetRegistered { asset_id: 0, asset: asset(Some(MultiLocation::parent())) }
+				.into(),
+		);
+		assert_ok!(AssetsRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn cannot_register_two_assets_at_the_same_location() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+
+		assert_noop!(
+			AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(Some(sibling(2000)))),
+			Error::<Test>::LocationAlreadyRegistered
+		);
+		assert_eq!(
+			LocationToAssetId::<Test>::get(VersionedMultiLocation::from(sibling(2000))),
+			Some(0)
+		);
+		assert_ok!(AssetsRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn updating_location_moves_the_index() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+
+		assert_ok!(AssetsRegistry::update_asset(
+			RuntimeOrigin::root(),
+			0,
+			asset(Some(sibling(3000)))
+		));
+
+		assert_eq!(
+			LocationToAssetId::<Test>::get(VersionedMultiLocation::from(sibling(2000))),
+			None
+		);
+		assert_eq!(
+			LocationToAssetId::<Test>::get(VersionedMultiLocation::from(sibling(3000))),
+			Some(0)
+		);
+		assert_ok!(AssetsRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn updating_location_to_none_removes_the_index() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+
+		assert_ok!(AssetsRegistry::update_asset(RuntimeOrigin::root(), 0, asset(None)));
+
+		assert_eq!(
+			LocationToAssetId::<Test>::get(VersionedMultiLocation::from(sibling(2000))),
+			None
+		);
+		assert_eq!(AssetsRegistry::get_asset_id_by_location(sibling(2000)), None);
+		assert_ok!(AssetsRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn cannot_update_to_a_location_owned_by_another_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(3000)))
+		));
+
+		assert_noop!(
+			AssetsRegistry::update_asset(RuntimeOrigin::root(), 1, asset(Some(sibling(2000)))),
+			Error::<Test>::LocationAlreadyRegistered
+		);
+		assert_ok!(AssetsRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn updating_asset_keeping_its_location_succeeds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+
+		let mut updated = asset(Some(sibling(2000)));
+		updated.decimals = 18;
+		assert_ok!(AssetsRegistry::update_asset(RuntimeOrigin::root(), 0, updated.clone()));
+
+		System::assert_last_event(
+			Event::AssetUpdated {
+				asset_id: 0,
+				old: asset(Some(sibling(2000))),
+				new: updated.clone(),
+				changed: vec![AssetField::Decimals],
+			}
+			.into(),
+		);
+		assert_eq!(AssetsMap::<Test>::get(0), Some(Asset::new(0, updated)));
+		assert_eq!(
+			LocationToAssetId::<Test>::get(VersionedMultiLocation::from(sibling(2000))),
+			Some(0)
+		);
+		assert_ok!(AssetsRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn update_event_lists_every_changed_field() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+
+		let mut updated = asset(Some(sibling(3000)));
+		updated.name = BoundedVec::truncate_from(b"Renamed asset".to_vec());
+		updated.existential_deposit = 1;
+		assert_ok!(AssetsRegistry::update_asset(RuntimeOrigin::root(), 0, updated.clone()));
+
+		System::assert_last_event(
+			Event::AssetUpdated {
+				asset_id: 0,
+				old: asset(Some(sibling(2000))),
+				new: updated,
+				changed: vec![
+					AssetField::Name,
+					AssetField::ExistentialDeposit,
+					AssetField::Location,
+				],
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn deleting_asset_removes_its_location() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+
+		assert_ok!(AssetsRegistry::delete_asset(RuntimeOrigin::root(), 0));
+
+		System::assert_last_event(
+			Event::AssetDeleted { asset_id: 0, asset: asset(Some(sibling(2000))) }.into(),
+		);
+		assert_eq!(
+			LocationToAssetId::<Test>::get(VersionedMultiLocation::from(sibling(2000))),
+			None
+		);
+		assert_eq!(AssetsRegistry::get_asset_by_location(sibling(2000)), None);
+		assert_ok!(AssetsRegistry::do_try_state());
+
+		// The location can be claimed again once freed.
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+		assert_eq!(
+			LocationToAssetId::<Test>::get(VersionedMultiLocation::from(sibling(2000))),
+			Some(1)
+		);
+	});
+}
+
+#[test]
+fn cannot_propose_asset_at_a_registered_location() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+
+		assert_noop!(
+			AssetsRegistry::propose_asset(RuntimeOrigin::signed(ALICE), asset(Some(sibling(2000)))),
+			Error::<Test>::LocationAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn try_state_detects_dangling_location() {
+	new_test_ext().execute_with(|| {
+		LocationToAssetId::<Test>::insert(VersionedMultiLocation::from(sibling(2000)), 1);
+
+		assert!(AssetsRegistry::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn existential_deposits_come_from_the_registry() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+		assert_eq!(RegistryExistentialDeposits::<Test>::get(&0), 10);
+
+		let mut updated = asset(None);
+		updated.existential_deposit = 50;
+		assert_ok!(AssetsRegistry::update_asset(RuntimeOrigin::root(), 0, updated));
+		assert_eq!(RegistryExistentialDeposits::<Test>::get(&0), 50);
+
+		// Unregistered assets have no existential deposit.
+		assert_eq!(RegistryExistentialDeposits::<Test>::get(&1), 0);
+	});
+}
+
+#[test]
+fn registration_allocates_sequential_ids() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+
+		assert!(AssetsRegistry::is_asset_registered(&0));
+		assert_eq!(AssetsMap::<Test>::get(1).map(|asset| asset.asset_id), Some(1));
+		assert_eq!(NextAssetIndex::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn failed_registration_does_not_consume_an_id() {
+	new_test_ext().execute_with(|| {
+		let mut invalid = asset(None);
+		invalid.name = BoundedVec::default();
+		assert_noop!(
+			AssetsRegistry::register_asset(RuntimeOrigin::root(), invalid),
+			Error::<Test>::InvalidAssetName
+		);
+
+		assert_eq!(NextAssetIndex::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn approved_proposal_gets_the_next_id() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+		assert_ok!(AssetsRegistry::propose_asset(
+			RuntimeOrigin::signed(ALICE),
+			asset(Some(sibling(2000)))
+		));
+
+		assert_ok!(AssetsRegistry::approve_asset_proposal(RuntimeOrigin::root(), 0));
+
+		System::assert_last_event(Event::AssetProposalApproved(0, 1).into());
+		assert_eq!(AssetsRegistry::get_asset_id_by_location(sibling(2000)), Some(1));
+		assert_eq!(Balances::free_balance(ALICE), ALICE_INITIAL_BALANCE);
+	});
+}
+
+fn xcm_context() -> XcmContext {
+	XcmContext { origin: None, message_hash: [0; 32], topic: None }
+}
+
+fn parent_asset(amount: u128) -> MultiAsset {
+	(Concrete(MultiLocation::parent()), amount).into()
+}
+
+#[test]
+fn units_per_second_can_only_be_set_for_registered_assets() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsRegistry::set_units_per_second(RuntimeOrigin::root(), 0, Some(1)),
+			Error::<Test>::AssetDoesNotExist
+		);
+
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+		assert_ok!(AssetsRegistry::set_units_per_second(RuntimeOrigin::root(), 0, Some(1)));
+		System::assert_last_event(Event::UnitsPerSecondChanged(0, Some(1)).into());
+		assert_eq!(AssetsRegistry::get_units_per_second(0), Some(1));
+
+		// Deleting the asset drops its rate.
+		assert_ok!(AssetsRegistry::delete_asset(RuntimeOrigin::root(), 0));
+		assert!(!UnitsPerSecond::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn trader_charges_registered_asset_and_refunds_unused_weight() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(MultiLocation::parent()))
+		));
+		// One unit per unit of ref time.
+		assert_ok!(AssetsRegistry::set_units_per_second(
+			RuntimeOrigin::root(),
+			0,
+			Some(WEIGHT_REF_TIME_PER_SECOND as u128)
+		));
+
+		let mut trader = RegistryWeightTrader::<Test, RevenueCollector>::new();
+		let unused = trader
+			.buy_weight(XcmWeight::from_parts(1_000, 0), parent_asset(5_000).into(), &xcm_context())
+			.unwrap();
+		assert_eq!(unused, Assets::from(parent_asset(4_000)));
+
+		assert_eq!(
+			trader.refund_weight(XcmWeight::from_parts(400, 0), &xcm_context()),
+			Some(parent_asset(400))
+		);
+
+		drop(trader);
+		assert_eq!(CollectedRevenue::get(), vec![parent_asset(600)]);
+	});
+}
+
+#[test]
+fn trader_rejects_assets_without_a_rate() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(MultiLocation::parent()))
+		));
+
+		let mut trader = RegistryWeightTrader::<Test, RevenueCollector>::new();
+		assert_eq!(
+			trader.buy_weight(
+				XcmWeight::from_parts(1_000, 0),
+				parent_asset(5_000).into(),
+				&xcm_context(),
+			),
+			Err(XcmError::TooExpensive)
+		);
+	});
+}
+
+#[test]
+fn trader_rejects_insufficient_payment() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(MultiLocation::parent()))
+		));
+		assert_ok!(AssetsRegistry::set_units_per_second(
+			RuntimeOrigin::root(),
+			0,
+			Some(WEIGHT_REF_TIME_PER_SECOND as u128)
+		));
+
+		let mut trader = RegistryWeightTrader::<Test, RevenueCollector>::new();
+		assert_eq!(
+			trader.buy_weight(
+				XcmWeight::from_parts(1_000, 0),
+				parent_asset(999).into(),
+				&xcm_context(),
+			),
+			Err(XcmError::TooExpensive)
+		);
+		drop(trader);
+		assert!(CollectedRevenue::get().is_empty());
+	});
+}
+
+#[test]
+fn min_xcm_fees_are_looked_up_by_reserve_location() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::set_min_xcm_fee(
+			RuntimeOrigin::root(),
+			Box::new(sibling(2000).into()),
+			40
+		));
+		System::assert_last_event(Event::MinXcmFeeSet(sibling(2000).into(), 40).into());
+
+		assert_eq!(RegistryMinXcmFees::<Test>::get(&sibling(2000)), Some(40));
+		assert_eq!(RegistryMinXcmFees::<Test>::get(&sibling(3000)), None);
+		assert_eq!(RegistryMinXcmFees::<Test>::get(&MultiLocation::parent()), None);
+
+		assert_ok!(AssetsRegistry::remove_min_xcm_fee(
+			RuntimeOrigin::root(),
+			Box::new(sibling(2000).into())
+		));
+		assert_eq!(RegistryMinXcmFees::<Test>::get(&sibling(2000)), None);
+		assert_noop!(
+			AssetsRegistry::remove_min_xcm_fee(
+				RuntimeOrigin::root(),
+				Box::new(sibling(2000).into())
+			),
+			Error::<Test>::MinXcmFeeDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn min_xcm_fees_are_looked_up_for_reserves_within_a_chain() {
+	new_test_ext().execute_with(|| {
+		let pallet_reserve = MultiLocation::new(1, X2(Parachain(1000), PalletInstance(50)));
+		assert_ok!(AssetsRegistry::set_min_xcm_fee(
+			RuntimeOrigin::root(),
+			Box::new(pallet_reserve.into()),
+			25
+		));
+		assert_ok!(AssetsRegistry::set_min_xcm_fee(
+			RuntimeOrigin::root(),
+			Box::new(MultiLocation::parent().into()),
+			10
+		));
+
+		assert_eq!(RegistryMinXcmFees::<Test>::get(&pallet_reserve), Some(25));
+		assert_eq!(RegistryMinXcmFees::<Test>::get(&MultiLocation::parent()), Some(10));
+		assert_eq!(RegistryMinXcmFees::<Test>::get(&sibling(1000)), None);
+	});
+}
+
+#[test]
+fn only_registry_origin_sets_min_xcm_fees() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsRegistry::set_min_xcm_fee(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(sibling(2000).into()),
+				40
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn assets_are_registered_active() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(AssetsRegistry::get_asset_status(0), None);
+
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+
+		assert_eq!(AssetsRegistry::get_asset_status(0), Some(AssetStatus::Active));
+	});
+}
+
+#[test]
+fn frozen_assets_cannot_be_transferred() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+		assert_ok!(AssetsRegistry::set_asset_status(RuntimeOrigin::root(), 0, AssetStatus::Frozen));
+		System::assert_last_event(Event::AssetStatusChanged(0, AssetStatus::Frozen).into());
+
+		assert_noop!(
+			<AssetStatusFilter<Test> as OnTransfer<u64, _, _>>::on_transfer(0, &ALICE, &2, 10),
+			Error::<Test>::AssetFrozen
+		);
+		assert_ok!(<AssetStatusFilter<Test> as OnDeposit<u64, _, _>>::on_deposit(0, &ALICE, 10));
+	});
+}
+
+#[test]
+fn deprecated_assets_can_only_be_withdrawn() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+		assert_ok!(AssetsRegistry::set_asset_status(
+			RuntimeOrigin::root(),
+			0,
+			AssetStatus::Deprecated
+		));
+
+		assert_noop!(
+			<AssetStatusFilter<Test> as OnTransfer<u64, _, _>>::on_transfer(0, &ALICE, &2, 10),
+			Error::<Test>::AssetDeprecated
+		);
+		assert_noop!(
+			<AssetStatusFilter<Test> as OnDeposit<u64, _, _>>::on_deposit(0, &ALICE, 10),
+			Error::<Test>::AssetDeprecated
+		);
+
+		// Back to active, the asset moves freely again.
+		assert_ok!(AssetsRegistry::set_asset_status(RuntimeOrigin::root(), 0, AssetStatus::Active));
+		assert_ok!(<AssetStatusFilter<Test> as OnTransfer<u64, _, _>>::on_transfer(
+			0, &ALICE, &2, 10
+		));
+	});
+}
+
+#[test]
+fn cannot_delete_asset_with_issuance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+		assert_ok!(AssetsRegistry::set_asset_status(
+			RuntimeOrigin::root(),
+			0,
+			AssetStatus::Deprecated
+		));
+		Issuance::set(1);
+
+		assert_noop!(
+			AssetsRegistry::delete_asset(RuntimeOrigin::root(), 0),
+			Error::<Test>::AssetStillInUse
+		);
+
+		Issuance::set(0);
+		assert_ok!(AssetsRegistry::delete_asset(RuntimeOrigin::root(), 0));
+		assert_eq!(AssetsRegistry::get_asset_status(0), None);
+		assert_ok!(AssetsRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn genesis_registers_assets() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		assets: vec![Asset::new(5, asset(Some(MultiLocation::parent())))],
+		units_per_second: vec![(5, 1_000)],
+		next_asset_index: 6,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(AssetsRegistry::get_asset_id_by_location(MultiLocation::parent()), Some(5));
+		assert_eq!(RegistryExistentialDeposits::<Test>::get(&5), 10);
+		assert_eq!(AssetsRegistry::get_units_per_second(5), Some(1_000));
+		assert_ok!(AssetsRegistry::do_try_state());
+
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+		assert!(AssetsRegistry::is_asset_registered(&6));
+	});
+}
+
+#[test]
+fn reader_returns_registered_asset_data() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(MultiLocation::parent()))
+		));
+
+		assert_eq!(
+			<AssetsRegistry as AssetRegistryReader<_, _>>::get_asset(0),
+			Some(Asset::new(0, asset(Some(MultiLocation::parent()))))
+		);
+		assert_eq!(AssetsRegistry::get_asset_name(0), Some(b"Test asset".to_vec()));
+		assert_eq!(AssetsRegistry::get_asset_symbol(0), Some(b"TEST".to_vec()));
+		assert_eq!(AssetsRegistry::get_asset_decimals(0), Some(12));
+		assert_eq!(AssetsRegistry::get_asset_existential_deposit(0), Some(10));
+	});
+}
+
+#[test]
+fn reader_returns_nothing_for_unknown_assets() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(<AssetsRegistry as AssetRegistryReader<_, _>>::get_asset(0), None);
+		assert_eq!(AssetsRegistry::get_asset_name(0), None);
+		assert_eq!(AssetsRegistry::get_asset_symbol(0), None);
+		assert_eq!(AssetsRegistry::get_asset_decimals(0), None);
+		assert_eq!(AssetsRegistry::get_asset_existential_deposit(0), None);
+		assert_eq!(AssetsRegistry::get_location_by_asset(0), None);
+	});
+}
+
+#[test]
+fn reader_looks_assets_up_by_location() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+
+		assert_eq!(
+			<AssetsRegistry as AssetRegistryReader<_, Balance>>::get_asset_id_by_location(
+				&sibling(2000)
+			),
+			Some(0)
+		);
+		assert_eq!(
+			<AssetsRegistry as AssetRegistryReader<_, Balance>>::get_location_by_asset(0),
+			Some(sibling(2000))
+		);
+		assert_eq!(
+			<AssetsRegistry as AssetRegistryReader<_, Balance>>::get_location_by_asset(1),
+			None
+		);
+		assert!(<AssetsRegistry as AssetRegistryReader<_, Balance>>::is_asset_registered(&1));
+		assert!(!<AssetsRegistry as AssetRegistryReader<_, Balance>>::is_asset_registered(&2));
+		let page = <AssetsRegistry as AssetRegistryReader<_, _>>::get_assets(None, 10);
+		assert_eq!(page.assets.len(), 2);
+		assert_eq!(page.next_cursor, None);
+	});
+}
+
+#[test]
+fn lists_assets_page_by_page() {
+	new_test_ext().execute_with(|| {
+		for para_id in 2000..2003 {
+			assert_ok!(AssetsRegistry::register_asset(
+				RuntimeOrigin::root(),
+				asset(Some(sibling(para_id)))
+			));
+		}
+
+		let first = AssetsRegistry::get_assets(None, 2);
+		assert_eq!(first.assets.len(), 2);
+		assert_eq!(first.next_cursor, Some(first.assets[1].asset_id));
+		let last = AssetsRegistry::get_assets(first.next_cursor, 2);
+		assert_eq!(last.assets.len(), 1);
+		assert_eq!(last.next_cursor, None);
+
+		// A zero limit gets the default page size rather than an empty page.
+		let page = AssetsRegistry::get_assets(None, 0);
+		assert_eq!(page.assets.len(), 3);
+		assert_eq!(page.next_cursor, None);
+	});
+}
+
+#[test]
+fn assets_are_stored_under_the_assets_storage_prefix() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(sibling(2000)))
+		));
+
+		let prefix = AssetsRegistry::assets_storage_prefix();
+		assert!(AssetsMap::<Test>::hashed_key_for(0).starts_with(&prefix));
+		assert_eq!(
+			prefix[..],
+			[sp_io::hashing::twox_128(b"AssetsRegistry"), sp_io::hashing::twox_128(b"AssetsMap")]
+				.concat()
+		);
+	});
+}
+
+#[test]
+fn empty_reader_has_no_assets() {
+	new_test_ext().execute_with(|| {
+		type Empty = ();
+		assert_eq!(<Empty as AssetRegistryReader<CurrencyId, Balance>>::get_asset(0), None);
+		assert_eq!(
+			<Empty as AssetRegistryReader<CurrencyId, Balance>>::get_asset_id_by_location(
+				&MultiLocation::parent()
+			),
+			None
+		);
+		assert!(!<Empty as AssetRegistryReader<CurrencyId, Balance>>::is_asset_registered(&0));
+		let page = <Empty as AssetRegistryReader<CurrencyId, Balance>>::get_assets(None, 10);
+		assert!(page.assets.is_empty());
+	});
+}
+
+#[test]
+fn in_memory_reader_serves_inserted_assets() {
+	type Registry = InMemoryAssetRegistry;
+	for asset_id in 0..3 {
+		Registry::insert(Asset::new(asset_id, asset(Some(sibling(2000 + asset_id)))));
+	}
+
+	assert_eq!(
+		<Registry as AssetRegistryReader<CurrencyId, Balance>>::get_asset(1),
+		Some(Asset::new(1, asset(Some(sibling(2001)))))
+	);
+	assert_eq!(
+		<Registry as AssetRegistryReader<CurrencyId, Balance>>::get_asset_id_by_location(&sibling(
+			2002
+		)),
+		Some(2)
+	);
+	assert_eq!(
+		<Registry as AssetRegistryReader<CurrencyId, Balance>>::get_location_by_asset(0),
+		Some(sibling(2000))
+	);
+	let page = <Registry as AssetRegistryReader<CurrencyId, Balance>>::get_assets(None, 2);
+	assert_eq!(page.assets.len(), 2);
+	let last =
+		<Registry as AssetRegistryReader<CurrencyId, Balance>>::get_assets(page.next_cursor, 2);
+	assert_eq!(last.assets.len(), 1);
+	assert_eq!(last.next_cursor, None);
+
+	Registry::remove(&1u32);
+	assert!(!<Registry as AssetRegistryReader<CurrencyId, Balance>>::is_asset_registered(&1));
+	Registry::clear();
+	assert_eq!(<Registry as AssetRegistryReader<CurrencyId, Balance>>::get_asset(0), None);
+}
+
+#[test]
+fn only_registry_origin_manages_assets() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsRegistry::register_asset(RuntimeOrigin::signed(ALICE), asset(None)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), asset(None)));
+		assert_noop!(
+			AssetsRegistry::update_asset(RuntimeOrigin::signed(ALICE), 0, asset(None)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetsRegistry::delete_asset(RuntimeOrigin::signed(ALICE), 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn cannot_update_or_delete_unknown_asset() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsRegistry::update_asset(RuntimeOrigin::root(), 0, asset(None)),
+			Error::<Test>::AssetDoesNotExist
+		);
+		assert_noop!(
+			AssetsRegistry::delete_asset(RuntimeOrigin::root(), 0),
+			Error::<Test>::AssetDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn invalid_metadata_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let mut invalid = asset(None);
+		invalid.symbol = BoundedVec::truncate_from(vec![0xff]);
+		assert_noop!(
+			AssetsRegistry::register_asset(RuntimeOrigin::root(), invalid),
+			Error::<Test>::InvalidAssetSymbol
+		);
+
+		let mut invalid = asset(None);
+		invalid.decimals = MAX_DECIMALS + 1;
+		assert_noop!(
+			AssetsRegistry::register_asset(RuntimeOrigin::root(), invalid),
+			Error::<Test>::InvalidAssetDecimals
+		);
+
+		let mut invalid = asset(None);
+		invalid.metadata.coingecko_id = Some(BoundedVec::truncate_from(vec![0xff]));
+		assert_noop!(
+			AssetsRegistry::register_asset(RuntimeOrigin::root(), invalid),
+			Error::<Test>::InvalidAssetMetadata
+		);
+	});
+}
+
+#[test]
+fn proposals_reserve_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::propose_asset(RuntimeOrigin::signed(ALICE), asset(None)));
+
+		System::assert_last_event(Event::AssetProposed(0, ALICE).into());
+		assert_eq!(Balances::reserved_balance(&ALICE), PROPOSAL_DEPOSIT);
+		assert!(!AssetsRegistry::is_asset_registered(&0));
+	});
+}
+
+#[test]
+fn rejected_proposals_lose_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::propose_asset(RuntimeOrigin::signed(ALICE), asset(None)));
+
+		assert_ok!(AssetsRegistry::reject_asset_proposal(RuntimeOrigin::root(), 0));
+
+		System::assert_last_event(Event::AssetProposalRejected(0).into());
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), ALICE_INITIAL_BALANCE - PROPOSAL_DEPOSIT);
+		assert!(!AssetsRegistry::is_asset_registered(&0));
+	});
+}
+
+#[test]
+fn cannot_propose_without_funds_for_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsRegistry::propose_asset(RuntimeOrigin::signed(2), asset(None)),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn proposal_indices_do_not_wrap_around() {
+	new_test_ext().execute_with(|| {
+		ProposalCount::<Test>::put(u32::MAX - 1);
+		assert_ok!(AssetsRegistry::propose_asset(RuntimeOrigin::signed(ALICE), asset(None)));
+
+		assert_noop!(
+			AssetsRegistry::propose_asset(RuntimeOrigin::signed(ALICE), asset(None)),
+			Error::<Test>::NoAvailableProposalIndex
+		);
+		assert!(AssetProposals::<Test>::contains_key(u32::MAX - 1));
+		assert_eq!(Balances::reserved_balance(&ALICE), PROPOSAL_DEPOSIT);
+	});
+}
+
+#[test]
+fn unknown_proposals_cannot_be_decided() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsRegistry::approve_asset_proposal(RuntimeOrigin::root(), 0),
+			Error::<Test>::ProposalDoesNotExist
+		);
+		assert_noop!(
+			AssetsRegistry::reject_asset_proposal(RuntimeOrigin::root(), 0),
+			Error::<Test>::ProposalDoesNotExist
+		);
+	});
+}
+
+fn v2_parent() -> VersionedMultiLocation {
+	VersionedMultiLocation::V2(xcm::v2::MultiLocation::parent())
+}
+
+#[test]
+fn locations_are_stored_in_the_current_xcm_version() {
+	new_test_ext().execute_with(|| {
+		let mut details = asset(None);
+		details.location = Some(v2_parent().into());
+		assert_ok!(AssetsRegistry::register_asset(RuntimeOrigin::root(), details));
+
+		assert_eq!(
+			AssetsMap::<Test>::get(0).and_then(|asset| asset.location),
+			Some(AssetLocation(VersionedMultiLocation::V3(MultiLocation::parent())))
+		);
+		assert_eq!(AssetsRegistry::get_asset_id_by_location(MultiLocation::parent()), Some(0));
+		assert_eq!(AssetsRegistry::get_asset_id_by_location(v2_parent()), Some(0));
+		assert_eq!(AssetsRegistry::get_location_by_asset(0), Some(MultiLocation::parent()));
+		assert_ok!(AssetsRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn storage_is_bounded() {
+	for info in <AssetsRegistry as StorageInfoTrait>::storage_info() {
+		assert!(info.max_size.is_some(), "{:?} is unbounded", info.storage_name);
+	}
+	assert_eq!(
+		AssetLocation::from(MultiLocation::parent()).encode(),
+		VersionedMultiLocation::from(MultiLocation::parent()).encode()
+	);
+}
+
+#[test]
+fn same_location_in_another_xcm_version_is_already_registered() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			asset(Some(MultiLocation::parent()))
+		));
+
+		let mut details = asset(None);
+		details.location = Some(v2_parent().into());
+		assert_noop!(
+			AssetsRegistry::register_asset(RuntimeOrigin::root(), details),
+			Error::<Test>::LocationAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn min_xcm_fees_are_looked_up_in_any_xcm_version() {
+	new_test_ext().execute_with(|| {
+		let v2_sibling = VersionedMultiLocation::V2(xcm::v2::MultiLocation::new(
+			1,
+			xcm::v2::Junctions::X1(xcm::v2::Junction::Parachain(2000)),
+		));
+		assert_ok!(AssetsRegistry::set_min_xcm_fee(
+			RuntimeOrigin::root(),
+			Box::new(v2_sibling.clone()),
+			40
+		));
+
+		System::assert_last_event(Event::MinXcmFeeSet(sibling(2000).into(), 40).into());
+		assert_eq!(RegistryMinXcmFees::<Test>::get(&sibling(2000)), Some(40));
+		assert_eq!(AssetsRegistry::min_xcm_fee(v2_sibling), Some(40));
+	});
+}
+
+#[test]
+fn migration_to_v1_truncates_names_between_characters() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<AssetsRegistry>();
+		// Every character but the first takes two bytes, so both bounds fall within one.
+		let name = format!("A{}", "é".repeat(20)).into_bytes();
+		let old_asset = (0u32, 12u8, name, 10 as Balance, Some(sibling(2000)));
+		frame_support::storage::unhashed::put(
+			&unversioned::AssetsMap::<Test>::hashed_key_for(0),
+			&old_asset,
+		);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		let asset = unversioned::AssetsMap::<Test>::get(0).unwrap();
+		assert_eq!(asset.name.into_inner(), format!("A{}", "é".repeat(15)).into_bytes());
+		assert_eq!(asset.symbol.into_inner(), format!("A{}", "é".repeat(5)).into_bytes());
+		let migrated = Asset::from(unversioned::AssetsMap::<Test>::get(0).unwrap());
+		assert_ok!(AssetsRegistry::ensure_valid_asset(&migrated.into()));
+	});
+}
+
+#[test]
+fn migration_to_v1_leaves_undecodable_assets_untouched() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<AssetsRegistry>();
+		let old_asset = (0u32, 12u8, b"Test asset".to_vec(), 10 as Balance, Some(sibling(2000)));
+		frame_support::storage::unhashed::put(
+			&unversioned::AssetsMap::<Test>::hashed_key_for(0),
+			&old_asset,
+		);
+		let undecodable_key = unversioned::AssetsMap::<Test>::hashed_key_for(1);
+		frame_support::storage::unhashed::put_raw(&undecodable_key, &[1, 2, 3]);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert!(unversioned::AssetsMap::<Test>::get(0).is_some());
+		assert_eq!(
+			frame_support::storage::unhashed::get_raw(&undecodable_key),
+			Some(vec![1, 2, 3])
+		);
+		assert_eq!(AssetsRegistry::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn migration_to_v3_versions_stored_locations() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<AssetsRegistry>();
+		let old_asset = unversioned::Asset {
+			asset_id: 0,
+			decimals: 12,
+			name: BoundedVec::truncate_from(b"Test asset".to_vec()),
+			symbol: BoundedVec::truncate_from(b"TEST".to_vec()),
+			existential_deposit: 10,
+			location: Some(sibling(2000)),
+			metadata: AssetMetadata::default(),
+		};
+		unversioned::AssetsMap::<Test>::insert(0, old_asset);
+		ExistentialDeposits::<Test>::insert(0, 10);
+		unversioned::LocationToAssetId::<Test>::insert(sibling(2000), 0);
+		unversioned::MinXcmFees::<Test>::insert(2000, sibling(2000), 40);
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(AssetsRegistry::on_chain_storage_version(), 3);
+		assert_eq!(AssetsMap::<Test>::get(0), Some(Asset::new(0, asset(Some(sibling(2000))))));
+		assert_eq!(AssetsRegistry::get_asset_id_by_location(sibling(2000)), Some(0));
+		assert_eq!(AssetsRegistry::min_xcm_fee(sibling(2000)), Some(40));
+		assert_ok!(AssetsRegistry::do_try_state());
+	});
+}
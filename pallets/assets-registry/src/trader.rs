@@ -0,0 +1,95 @@
+use crate::pallet::{Config, Pallet};
+use frame_support::{pallet_prelude::PhantomData, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
+use xcm::v3::{prelude::*, Weight as XcmWeight};
+use xcm_builder::TakeRevenue;
+use xcm_executor::{traits::WeightTrader, Assets};
+
+/// Charges XCM execution with any registered asset that has an execution rate set in the
+/// registry, giving the revenue to `R` once done.
+///
+/// Weight can only be bought with a single asset per message: the first one used with a rate
+/// set is the one charged for the whole execution.
+pub struct RegistryWeightTrader<T: Config, R: TakeRevenue> {
+	/// Weight bought so far.
+	weight: XcmWeight,
+	/// Amount charged so far.
+	amount: u128,
+	/// Location and rate of the asset used for payment.
+	asset: Option<(MultiLocation, u128)>,
+	_marker: PhantomData<(T, R)>,
+}
+
+impl<T: Config, R: TakeRevenue> RegistryWeightTrader<T, R> {
+	fn units_per_second(location: &MultiLocation) -> Option<u128> {
+		let asset_id = Pallet::<T>::get_asset_id_by_location(*location)?;
+		Pallet::<T>::get_units_per_second(asset_id)
+	}
+
+	fn amount_for(weight: XcmWeight, units_per_second: u128) -> u128 {
+		units_per_second.saturating_mul(weight.ref_time() as u128) /
+			(WEIGHT_REF_TIME_PER_SECOND as u128)
+	}
+}
+
+impl<T: Config, R: TakeRevenue> WeightTrader for RegistryWeightTrader<T, R> {
+	fn new() -> Self {
+		Self { weight: XcmWeight::zero(), amount: 0, asset: None, _marker: PhantomData }
+	}
+
+	fn buy_weight(
+		&mut self,
+		weight: XcmWeight,
+		payment: Assets,
+		_context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		let (location, units_per_second) = match self.asset {
+			Some(asset) => asset,
+			None => payment
+				.fungible
+				.keys()
+				.find_map(|asset_id| match asset_id {
+					Concrete(location) =>
+						Self::units_per_second(location).map(|rate| (*location, rate)),
+					_ => None,
+				})
+				.ok_or(XcmError::TooExpensive)?,
+		};
+
+		let amount = Self::amount_for(weight, units_per_second);
+		if amount == 0 {
+			return Ok(payment)
+		}
+		let required: MultiAsset = (Concrete(location), amount).into();
+		let unused = payment.checked_sub(required).map_err(|_| XcmError::TooExpensive)?;
+
+		self.weight = self.weight.saturating_add(weight);
+		self.amount = self.amount.saturating_add(amount);
+		self.asset = Some((location, units_per_second));
+
+		Ok(unused)
+	}
+
+	fn refund_weight(&mut self, weight: XcmWeight, _context: &XcmContext) -> Option<MultiAsset> {
+		let (location, units_per_second) = self.asset?;
+		let weight = weight.min(self.weight);
+		let amount = Self::amount_for(weight, units_per_second).min(self.amount);
+		self.weight = self.weight.saturating_sub(weight);
+		self.amount = self.amount.saturating_sub(amount);
+
+		if amount > 0 {
+			Some((Concrete(location), amount).into())
+		} else {
+			None
+		}
+	}
+}
+
+impl<T: Config, R: TakeRevenue> Drop for RegistryWeightTrader<T, R> {
+	fn drop(&mut self) {
+		if let Some((location, _)) = self.asset {
+			if self.amount > 0 {
+				R::take_revenue((Concrete(location), self.amount).into());
+			}
+		}
+	}
+}
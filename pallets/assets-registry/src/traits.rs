@@ -1,20 +1,361 @@
 pub use crate::*;
 
+use codec::EncodeLike;
 use frame_support::{inherent::Vec, pallet_prelude::*};
-use xcm::v3::MultiLocation;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
-#[derive(Encode, Decode, Default, PartialEq, Eq, TypeInfo, Clone, Debug)]
+/// Maximum length of an asset name, in bytes.
+pub const MAX_NAME_LENGTH: u32 = 32;
+/// Maximum length of an asset symbol, in bytes.
+pub const MAX_SYMBOL_LENGTH: u32 = 12;
+/// Maximum length of an asset price aggregator id, in bytes.
+pub const MAX_COINGECKO_ID_LENGTH: u32 = 64;
+/// Maximum number of decimals an asset can have.
+pub const MAX_DECIMALS: u8 = 18;
+
+pub type AssetName = BoundedVec<u8, ConstU32<MAX_NAME_LENGTH>>;
+pub type AssetSymbol = BoundedVec<u8, ConstU32<MAX_SYMBOL_LENGTH>>;
+pub type CoingeckoId = BoundedVec<u8, ConstU32<MAX_COINGECKO_ID_LENGTH>>;
+
+#[derive(Encode, Decode, Default, PartialEq, Eq, TypeInfo, Clone, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Asset<RegisteredAssetId, Balance> {
 	pub asset_id: RegisteredAssetId,
 	pub decimals: u8,
-	pub name: Vec<u8>,
+	pub name: AssetName,
+	pub symbol: AssetSymbol,
+	pub existential_deposit: Balance,
+	/// Location of the asset, kept in the current XCM version.
+	pub location: Option<AssetLocation>,
+	pub metadata: AssetMetadata,
+}
+
+/// Asset data provided on registration, the id being allocated by the registry.
+#[derive(Encode, Decode, Default, PartialEq, Eq, TypeInfo, Clone, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetDetails<Balance> {
+	pub decimals: u8,
+	pub name: AssetName,
+	pub symbol: AssetSymbol,
 	pub existential_deposit: Balance,
-	pub location: Option<MultiLocation>,
+	pub location: Option<AssetLocation>,
+	pub metadata: AssetMetadata,
+}
+
+/// Location stored by the registry, in the current XCM version.
+///
+/// Encoded as the `VersionedMultiLocation` it wraps, but bounded by the size of a location in the
+/// current XCM version since the registry converts locations to it before storing them. The
+/// bound has to move along with the XCM version locations are normalized to.
+#[derive(Encode, Decode, PartialEq, Eq, TypeInfo, Clone, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(transparent))]
+pub struct AssetLocation(pub VersionedMultiLocation);
+
+impl MaxEncodedLen for AssetLocation {
+	fn max_encoded_len() -> usize {
+		// The version byte, followed by the location.
+		1 + MultiLocation::max_encoded_len()
+	}
+}
+
+impl EncodeLike<VersionedMultiLocation> for AssetLocation {}
+impl EncodeLike<AssetLocation> for VersionedMultiLocation {}
+
+impl From<MultiLocation> for AssetLocation {
+	fn from(location: MultiLocation) -> Self {
+		AssetLocation(location.into())
+	}
+}
+
+impl From<VersionedMultiLocation> for AssetLocation {
+	fn from(location: VersionedMultiLocation) -> Self {
+		AssetLocation(location)
+	}
+}
+
+impl From<AssetLocation> for VersionedMultiLocation {
+	fn from(location: AssetLocation) -> Self {
+		location.0
+	}
+}
+
+impl TryFrom<AssetLocation> for MultiLocation {
+	type Error = ();
+
+	fn try_from(location: AssetLocation) -> Result<Self, ()> {
+		location.0.try_into()
+	}
+}
+
+impl<RegisteredAssetId, Balance> Asset<RegisteredAssetId, Balance> {
+	pub fn new(asset_id: RegisteredAssetId, details: AssetDetails<Balance>) -> Self {
+		Asset {
+			asset_id,
+			decimals: details.decimals,
+			name: details.name,
+			symbol: details.symbol,
+			existential_deposit: details.existential_deposit,
+			location: details.location,
+			metadata: details.metadata,
+		}
+	}
+}
+
+impl<Balance: PartialEq> AssetDetails<Balance> {
+	/// Fields that differ between `self` and `other`.
+	pub fn changed_fields(&self, other: &Self) -> Vec<AssetField> {
+		let mut changed = Vec::new();
+		if self.decimals != other.decimals {
+			changed.push(AssetField::Decimals);
+		}
+		if self.name != other.name {
+			changed.push(AssetField::Name);
+		}
+		if self.symbol != other.symbol {
+			changed.push(AssetField::Symbol);
+		}
+		if self.existential_deposit != other.existential_deposit {
+			changed.push(AssetField::ExistentialDeposit);
+		}
+		if self.location != other.location {
+			changed.push(AssetField::Location);
+		}
+		if self.metadata != other.metadata {
+			changed.push(AssetField::Metadata);
+		}
+		changed
+	}
+}
+
+/// Asset fields, used to report which ones changed on updates.
+#[derive(Encode, Decode, PartialEq, Eq, TypeInfo, Clone, Copy, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AssetField {
+	Decimals,
+	Name,
+	Symbol,
+	ExistentialDeposit,
+	Location,
+	Metadata,
+}
+
+impl<RegisteredAssetId, Balance> From<Asset<RegisteredAssetId, Balance>> for AssetDetails<Balance> {
+	fn from(asset: Asset<RegisteredAssetId, Balance>) -> Self {
+		AssetDetails {
+			decimals: asset.decimals,
+			name: asset.name,
+			symbol: asset.symbol,
+			existential_deposit: asset.existential_deposit,
+			location: asset.location,
+			metadata: asset.metadata,
+		}
+	}
+}
+
+/// Optional asset metadata, only meant for off-chain consumers.
+#[derive(Encode, Decode, Default, PartialEq, Eq, TypeInfo, Clone, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetMetadata {
+	/// Id of the asset in price aggregators such as coingecko, e.g. `polkadot`.
+	pub coingecko_id: Option<CoingeckoId>,
+	/// Hash of the asset icon.
+	pub icon_hash: Option<[u8; 32]>,
+}
+
+/// Lifecycle state of a registered asset.
+#[derive(Encode, Decode, Default, PartialEq, Eq, TypeInfo, Clone, Copy, Debug, MaxEncodedLen)]
+pub enum AssetStatus {
+	/// The asset can be freely deposited, transferred and withdrawn.
+	#[default]
+	Active,
+	/// Transfers between accounts are rejected.
+	Frozen,
+	/// The asset is being phased out: it can only be withdrawn.
+	Deprecated,
+}
+
+/// Asset registration proposed by a regular account, pending governance approval.
+#[derive(Encode, Decode, PartialEq, Eq, TypeInfo, Clone, Debug, MaxEncodedLen)]
+pub struct AssetProposal<AccountId, Deposit, Balance> {
+	/// Account that made the proposal and had the deposit reserved.
+	pub proposer: AccountId,
+	/// Amount reserved from the proposer.
+	pub deposit: Deposit,
+	/// Asset that gets registered if the proposal is approved.
+	pub asset: AssetDetails<Balance>,
+}
+
+/// A page of registered assets, as returned by the paginated asset listing.
+#[derive(Encode, Decode, Default, PartialEq, Eq, TypeInfo, Clone, Debug)]
+pub struct AssetsPage<RegisteredAssetId, Balance> {
+	pub assets: Vec<Asset<RegisteredAssetId, Balance>>,
+	/// Cursor to pass in order to get the next page, `None` if this is the last one.
+	pub next_cursor: Option<RegisteredAssetId>,
+}
+
+/// Builds the id of a newly registered asset out of a sequential index.
+///
+/// Lets runtimes decide which kind of id an asset gets, e.g. depending on whether it lives in
+/// this chain or comes from another consensus system.
+pub trait AllocateAssetId<CurrencyId> {
+	fn allocate(index: u32, location: Option<&MultiLocation>) -> CurrencyId;
 }
 
 pub trait AssetRegistryReader<CurrencyId, Balance> {
 	fn get_asset(asset_id: CurrencyId) -> Option<Asset<CurrencyId, Balance>>;
 	fn get_asset_name(asset_id: CurrencyId) -> Option<Vec<u8>>;
+	fn get_asset_symbol(asset_id: CurrencyId) -> Option<Vec<u8>>;
 	fn get_asset_decimals(asset_id: CurrencyId) -> Option<u8>;
 	fn get_asset_existential_deposit(asset_id: CurrencyId) -> Option<Balance>;
+	/// Id of the asset registered at `location`.
+	fn get_asset_id_by_location(location: &MultiLocation) -> Option<CurrencyId>;
+	/// Location of the asset, in the current XCM version.
+	fn get_location_by_asset(asset_id: CurrencyId) -> Option<MultiLocation>;
+	fn is_asset_registered(asset_id: &CurrencyId) -> bool;
+	/// Returns up to `limit` registered assets, starting right after `cursor`.
+	fn get_assets(cursor: Option<CurrencyId>, limit: u32) -> AssetsPage<CurrencyId, Balance>;
+}
+
+/// Registry without any asset, for runtimes that do not need one.
+impl<CurrencyId, Balance> AssetRegistryReader<CurrencyId, Balance> for () {
+	fn get_asset(_asset_id: CurrencyId) -> Option<Asset<CurrencyId, Balance>> {
+		None
+	}
+	fn get_asset_name(_asset_id: CurrencyId) -> Option<Vec<u8>> {
+		None
+	}
+	fn get_asset_symbol(_asset_id: CurrencyId) -> Option<Vec<u8>> {
+		None
+	}
+	fn get_asset_decimals(_asset_id: CurrencyId) -> Option<u8> {
+		None
+	}
+	fn get_asset_existential_deposit(_asset_id: CurrencyId) -> Option<Balance> {
+		None
+	}
+	fn get_asset_id_by_location(_location: &MultiLocation) -> Option<CurrencyId> {
+		None
+	}
+	fn get_location_by_asset(_asset_id: CurrencyId) -> Option<MultiLocation> {
+		None
+	}
+	fn is_asset_registered(_asset_id: &CurrencyId) -> bool {
+		false
+	}
+	fn get_assets(_cursor: Option<CurrencyId>, _limit: u32) -> AssetsPage<CurrencyId, Balance> {
+		AssetsPage { assets: Vec::new(), next_cursor: None }
+	}
+}
+
+#[cfg(feature = "std")]
+use std::{cell::RefCell, collections::BTreeMap, ops::Bound};
+
+#[cfg(feature = "std")]
+thread_local! {
+	static IN_MEMORY_ASSETS: RefCell<BTreeMap<Vec<u8>, Vec<u8>>> =
+		Default::default();
+}
+
+/// Registry kept in memory, for the mock runtimes of pallets that depend on
+/// `AssetRegistryReader`.
+///
+/// Assets are kept per thread, so every test starts with an empty registry. They are stored
+/// encoded and keyed by their encoded id, which is also the order they are listed in.
+#[cfg(feature = "std")]
+pub struct InMemoryAssetRegistry;
+
+#[cfg(feature = "std")]
+impl InMemoryAssetRegistry {
+	/// Registers `asset`, replacing any asset registered under the same id.
+	pub fn insert<CurrencyId: Encode, Balance: Encode>(asset: Asset<CurrencyId, Balance>) {
+		IN_MEMORY_ASSETS
+			.with(|assets| assets.borrow_mut().insert(asset.asset_id.encode(), asset.encode()));
+	}
+
+	/// Removes the asset registered under `asset_id`.
+	pub fn remove<CurrencyId: Encode>(asset_id: &CurrencyId) {
+		IN_MEMORY_ASSETS.with(|assets| assets.borrow_mut().remove(&asset_id.encode()));
+	}
+
+	/// Removes every registered asset.
+	pub fn clear() {
+		IN_MEMORY_ASSETS.with(|assets| assets.borrow_mut().clear());
+	}
+
+	fn asset<CurrencyId: Encode, Balance: Decode>(
+		asset_id: &CurrencyId,
+	) -> Option<Asset<CurrencyId, Balance>> {
+		IN_MEMORY_ASSETS.with(|assets| {
+			let assets = assets.borrow();
+			Decode::decode(&mut &assets.get(&asset_id.encode())?[..]).ok()
+		})
+	}
+
+	fn assets_after<CurrencyId: Encode + Decode, Balance: Decode>(
+		cursor: Option<&CurrencyId>,
+	) -> Vec<Asset<CurrencyId, Balance>> {
+		IN_MEMORY_ASSETS.with(|assets| {
+			let assets = assets.borrow();
+			let after = match cursor {
+				Some(asset_id) => Bound::Excluded(asset_id.encode()),
+				None => Bound::Unbounded,
+			};
+			assets
+				.range((after, Bound::Unbounded))
+				.filter_map(|(_, asset)| Decode::decode(&mut &asset[..]).ok())
+				.collect()
+		})
+	}
+}
+
+#[cfg(feature = "std")]
+impl<CurrencyId, Balance> AssetRegistryReader<CurrencyId, Balance> for InMemoryAssetRegistry
+where
+	CurrencyId: Encode + Decode + Clone,
+	Balance: Decode,
+{
+	fn get_asset(asset_id: CurrencyId) -> Option<Asset<CurrencyId, Balance>> {
+		Self::asset(&asset_id)
+	}
+	fn get_asset_name(asset_id: CurrencyId) -> Option<Vec<u8>> {
+		Self::asset::<_, Balance>(&asset_id).map(|asset| asset.name.into_inner())
+	}
+	fn get_asset_symbol(asset_id: CurrencyId) -> Option<Vec<u8>> {
+		Self::asset::<_, Balance>(&asset_id).map(|asset| asset.symbol.into_inner())
+	}
+	fn get_asset_decimals(asset_id: CurrencyId) -> Option<u8> {
+		Self::asset::<_, Balance>(&asset_id).map(|asset| asset.decimals)
+	}
+	fn get_asset_existential_deposit(asset_id: CurrencyId) -> Option<Balance> {
+		Self::asset(&asset_id).map(|asset| asset.existential_deposit)
+	}
+	fn get_asset_id_by_location(location: &MultiLocation) -> Option<CurrencyId> {
+		Self::assets_after::<CurrencyId, Balance>(None)
+			.into_iter()
+			.find(|asset| {
+				asset.location.clone().and_then(|location| location.try_into().ok()) ==
+					Some(*location)
+			})
+			.map(|asset| asset.asset_id)
+	}
+	fn get_location_by_asset(asset_id: CurrencyId) -> Option<MultiLocation> {
+		Self::asset::<_, Balance>(&asset_id)?.location?.try_into().ok()
+	}
+	fn is_asset_registered(asset_id: &CurrencyId) -> bool {
+		IN_MEMORY_ASSETS.with(|assets| assets.borrow().contains_key(&asset_id.encode()))
+	}
+	fn get_assets(cursor: Option<CurrencyId>, limit: u32) -> AssetsPage<CurrencyId, Balance> {
+		let limit = match limit {
+			0 => DEFAULT_ASSETS_PAGE_SIZE,
+			limit => limit.min(MAX_ASSETS_PAGE_SIZE),
+		} as usize;
+		let mut assets = Self::assets_after(cursor.as_ref());
+		let next_cursor = (assets.len() > limit).then(|| {
+			assets.truncate(limit);
+			assets[limit - 1].asset_id.clone()
+		});
+		AssetsPage { assets, next_cursor }
+	}
 }
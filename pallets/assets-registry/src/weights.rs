@@ -0,0 +1,183 @@
+//! Weights for `assets_registry`
+//!
+//! NOT BENCHMARKED: these weights are estimated by hand from the storage accesses of each call,
+//! and are no measurement. They only follow the layout of the benchmark CLI so that
+//! `scripts/benchmark.sh` can overwrite them with the output of
+//! `benchmark pallet --pallet assets_registry`, which has to be done before the runtime is
+//! deployed. Proof sizes count the largest encoding of each item read.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{constants::RocksDbWeight, Weight}};
+use core::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn register_asset() -> Weight;
+	fn update_asset() -> Weight;
+	fn delete_asset() -> Weight;
+	fn propose_asset() -> Weight;
+	fn approve_asset_proposal() -> Weight;
+	fn reject_asset_proposal() -> Weight;
+	fn set_units_per_second() -> Weight;
+	fn set_min_xcm_fee() -> Weight;
+	fn remove_min_xcm_fee() -> Weight;
+	fn set_asset_status() -> Weight;
+}
+
+/// Weight functions for `assets_registry`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AssetsRegistry AssetsMap (r:1 w:1)
+	/// Storage: AssetsRegistry LocationToAssetId (r:1 w:1)
+	/// Storage: AssetsRegistry NextAssetIndex (r:1 w:1)
+	/// Storage: AssetsRegistry ExistentialDeposits (r:0 w:1)
+	fn register_asset() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6849))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: AssetsRegistry AssetsMap (r:1 w:1)
+	/// Storage: AssetsRegistry LocationToAssetId (r:1 w:2)
+	/// Storage: AssetsRegistry ExistentialDeposits (r:0 w:1)
+	fn update_asset() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6350))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: AssetsRegistry AssetsMap (r:2 w:1)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Storage: AssetsRegistry ExistentialDeposits (r:0 w:1)
+	/// Storage: AssetsRegistry AssetStatuses (r:0 w:1)
+	/// Storage: AssetsRegistry UnitsPerSecond (r:0 w:1)
+	/// Storage: AssetsRegistry LocationToAssetId (r:0 w:1)
+	fn delete_asset() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9022))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: AssetsRegistry LocationToAssetId (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AssetsRegistry ProposalCount (r:1 w:1)
+	/// Storage: AssetsRegistry AssetProposals (r:0 w:1)
+	fn propose_asset() -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6193))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: AssetsRegistry AssetProposals (r:1 w:1)
+	/// Storage: AssetsRegistry NextAssetIndex (r:1 w:1)
+	/// Storage: AssetsRegistry AssetsMap (r:1 w:1)
+	/// Storage: AssetsRegistry LocationToAssetId (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: AssetsRegistry ExistentialDeposits (r:0 w:1)
+	fn approve_asset_proposal() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 12753))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: AssetsRegistry AssetProposals (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn reject_asset_proposal() -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5904))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: AssetsRegistry AssetsMap (r:1 w:0)
+	/// Storage: AssetsRegistry UnitsPerSecond (r:0 w:1)
+	fn set_units_per_second() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3259))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetsRegistry MinXcmFees (r:0 w:1)
+	fn set_min_xcm_fee() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetsRegistry MinXcmFees (r:1 w:1)
+	fn remove_min_xcm_fee() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3102))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AssetsRegistry AssetsMap (r:1 w:0)
+	/// Storage: AssetsRegistry AssetStatuses (r:0 w:1)
+	fn set_asset_status() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3259))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn register_asset() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6849))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn update_asset() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6350))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	fn delete_asset() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9022))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	fn propose_asset() -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6193))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn approve_asset_proposal() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 12753))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	fn reject_asset_proposal() -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5904))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn set_units_per_second() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3259))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn set_min_xcm_fee() -> Weight {
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn remove_min_xcm_fee() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3102))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn set_asset_status() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3259))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,45 @@
+use crate::pallet::{Config, Pallet};
+use frame_support::{
+	pallet_prelude::PhantomData,
+	traits::{Contains, ContainsPair},
+};
+use sp_std::vec::Vec;
+use xcm::v3::{AssetId::Concrete, Junction::Parachain, Junctions::*, MultiAsset, MultiLocation};
+
+/// Origins trusted to execute XCM on this chain: the relay chain and the sibling parachains
+/// trusted by governance, meant to be used with the XCM barriers.
+pub struct TrustedOrigins<T>(PhantomData<T>);
+impl<T: Config> Contains<MultiLocation> for TrustedOrigins<T> {
+	fn contains(location: &MultiLocation) -> bool {
+		match location {
+			MultiLocation { parents: 1, interior: Here } => true,
+			MultiLocation { parents: 1, interior: X1(Parachain(para_id)) } =>
+				Pallet::<T>::is_trusted_sibling(*para_id),
+			_ => false,
+		}
+	}
+}
+
+/// Locations trusted by governance to teleport an asset, meant to be used as the XCM executor's
+/// `IsTeleporter`. Assets are only teleported by trusted locations, none by default.
+pub struct IsTrustedTeleporter<T>(PhantomData<T>);
+impl<T: Config> ContainsPair<MultiAsset, MultiLocation> for IsTrustedTeleporter<T> {
+	fn contains(asset: &MultiAsset, teleporter: &MultiLocation) -> bool {
+		match &asset.id {
+			Concrete(location) => Pallet::<T>::is_trusted_teleporter(location, teleporter),
+			_ => false,
+		}
+	}
+}
+
+/// Assets that some location is trusted to teleport, meant to be used as `pallet_xcm`'s
+/// `XcmTeleportFilter`. The destination of a teleport is checked once the assets are checked out.
+pub struct TeleportableAssets<T>(PhantomData<T>);
+impl<T: Config> Contains<(MultiLocation, Vec<MultiAsset>)> for TeleportableAssets<T> {
+	fn contains((_origin, assets): &(MultiLocation, Vec<MultiAsset>)) -> bool {
+		assets.iter().all(|asset| match &asset.id {
+			Concrete(location) => Pallet::<T>::is_teleportable(location),
+			_ => false,
+		})
+	}
+}
@@ -0,0 +1,86 @@
+//! Benchmarking setup for xcm-governance
+
+use super::*;
+use crate::traits::RemoteCallConfig;
+
+#[allow(unused)]
+use crate::Pallet as XcmGovernance;
+use frame_benchmarking::{v1::benchmarks, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
+use sp_std::{boxed::Box, vec};
+use xcm::v3::{Junction::Parachain, Junctions::X1, MultiLocation, Weight};
+
+fn sibling() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(2000)))
+}
+
+benchmarks! {
+	add_trusted_sibling {
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, 2000)
+	verify {
+		assert!(XcmGovernance::<T>::is_trusted_sibling(2000));
+	}
+
+	remove_trusted_sibling {
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		XcmGovernance::<T>::do_add_trusted_sibling(2000).map_err(|e| e.error)?;
+	}: _<T::RuntimeOrigin>(origin, 2000)
+	verify {
+		assert!(!XcmGovernance::<T>::is_trusted_sibling(2000));
+	}
+
+	add_trusted_teleporter {
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let asset = Box::new(MultiLocation::here().into());
+		let teleporter = Box::new(sibling().into());
+	}: _<T::RuntimeOrigin>(origin, asset, teleporter)
+	verify {
+		assert!(XcmGovernance::<T>::is_trusted_teleporter(&MultiLocation::here(), &sibling()));
+	}
+
+	remove_trusted_teleporter {
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		XcmGovernance::<T>::do_add_trusted_teleporter(MultiLocation::here(), sibling())
+			.map_err(|e| e.error)?;
+		let asset = Box::new(MultiLocation::here().into());
+		let teleporter = Box::new(sibling().into());
+	}: _<T::RuntimeOrigin>(origin, asset, teleporter)
+	verify {
+		assert!(!XcmGovernance::<T>::is_trusted_teleporter(&MultiLocation::here(), &sibling()));
+	}
+
+	set_remote_call_config {
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let dest = Box::new(MultiLocation::parent().into());
+		let fee_asset = Box::new(MultiLocation::here().into());
+		let weight_limit = Weight::from_parts(1_000_000_000, 64 * 1024);
+	}: _<T::RuntimeOrigin>(origin, dest, fee_asset, 1_000_000_000, weight_limit)
+	verify {
+		assert!(XcmGovernance::<T>::remote_call_config(&MultiLocation::parent()).is_some());
+	}
+
+	send_remote_call {
+		let c in 0 .. 16 * 1024;
+		let origin =
+			T::RemoteCallOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let weight_limit = Weight::from_parts(1_000_000_000, 64 * 1024);
+		XcmGovernance::<T>::do_set_remote_call_config(
+			MultiLocation::parent(),
+			Some(RemoteCallConfig {
+				fee_asset: MultiLocation::here(),
+				fee_amount: 1_000_000_000,
+				weight_limit,
+			}),
+		)
+		.map_err(|e| e.error)?;
+		let dest = Box::new(MultiLocation::parent().into());
+	}: _<T::RuntimeOrigin>(origin, dest, vec![0; c as usize], weight_limit)
+
+	impl_benchmark_test_suite!(XcmGovernance, crate::mock::new_test_ext(), crate::mock::Test);
+}
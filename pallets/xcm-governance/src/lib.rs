@@ -0,0 +1,472 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod adapters;
+pub mod tracing;
+pub mod traits;
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{traits::RemoteCallConfig, weights::WeightInfo};
+	use frame_support::{inherent::Vec, pallet_prelude::*};
+	use frame_system::pallet_prelude::OriginFor;
+	use sp_core::H256;
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+	use sp_std::boxed::Box;
+	use xcm::{
+		v3::{
+			send_xcm,
+			Instruction::{BuyExecution, DepositAsset, RefundSurplus, Transact, WithdrawAsset},
+			InteriorMultiLocation, MultiAsset,
+			MultiAssetFilter::Wild,
+			MultiAssets, MultiLocation, OriginKind, SendXcm,
+			WeightLimit::Limited,
+			WildMultiAsset::AllCounted,
+			Xcm, XcmHash,
+		},
+		VersionedMultiAssets, VersionedMultiLocation,
+	};
+
+	#[pallet::pallet]
+	// `VersionedMultiAssets` has no bounded encoding.
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// Sibling parachains trusted to execute XCM on this chain, besides the relay chain.
+	#[pallet::storage]
+	pub(super) type TrustedSiblings<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, (), OptionQuery>;
+
+	/// Locations trusted to teleport each asset, the asset being given by its location here.
+	#[pallet::storage]
+	pub(super) type TrustedTeleporters<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MultiLocation,
+		Blake2_128Concat,
+		MultiLocation,
+		(),
+		OptionQuery,
+	>;
+
+	/// How calls sent with `send_remote_call` are paid for, by destination.
+	#[pallet::storage]
+	pub(super) type RemoteCallConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, RemoteCallConfig, OptionQuery>;
+
+	/// Assets trapped by `XcmPallet` for each origin, by the hash `XcmPallet` counts them under.
+	///
+	/// Entries are removed once all the traps with the same hash have been claimed, or when
+	/// more than `MaxTrapsPerOrigin` traps are listed for the origin.
+	#[pallet::storage]
+	pub(super) type TrappedAssetsByOrigin<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MultiLocation,
+		Identity,
+		H256,
+		VersionedMultiAssets,
+		OptionQuery,
+	>;
+
+	/// Hashes of the traps listed in `TrappedAssetsByOrigin` for each origin, oldest first.
+	#[pallet::storage]
+	pub(super) type TrapHashesByOrigin<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		MultiLocation,
+		BoundedVec<H256, T::MaxTrapsPerOrigin>,
+		ValueQuery,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {
+		/// Sibling parachains trusted at genesis.
+		pub trusted_siblings: Vec<ParaId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			Self { trusted_siblings: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			for para_id in &self.trusted_siblings {
+				TrustedSiblings::<T>::insert(para_id, ());
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Sibling parachain has been added to the trusted origins.
+		TrustedSiblingAdded(ParaId),
+		/// Sibling parachain has been removed from the trusted origins.
+		TrustedSiblingRemoved(ParaId),
+		/// Location has been trusted to teleport an asset.
+		TrustedTeleporterAdded { asset: VersionedMultiLocation, teleporter: VersionedMultiLocation },
+		/// Location is no longer trusted to teleport an asset.
+		TrustedTeleporterRemoved {
+			asset: VersionedMultiLocation,
+			teleporter: VersionedMultiLocation,
+		},
+		/// How calls are paid for on a destination has been set, or removed if `None`.
+		RemoteCallConfigSet { dest: VersionedMultiLocation, config: Option<RemoteCallConfig> },
+		/// Call has been sent to a destination, to be dispatched from the sovereign account.
+		RemoteCallSent { dest: VersionedMultiLocation, message_hash: XcmHash },
+		/// Assets left in the holding register after executing XCM of `origin` have been dropped,
+		/// those below their existential deposit going to the treasury and the rest being trapped.
+		AssetsDropped {
+			origin: VersionedMultiLocation,
+			to_treasury: VersionedMultiAssets,
+			trapped: VersionedMultiAssets,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Sibling parachain is already trusted.
+		SiblingAlreadyTrusted,
+		/// Sibling parachain is not trusted.
+		SiblingNotTrusted,
+		/// Location is already trusted to teleport the asset.
+		TeleporterAlreadyTrusted,
+		/// Location is not trusted to teleport the asset.
+		TeleporterNotTrusted,
+		/// Location cannot be converted to the current XCM version.
+		UnsupportedLocationVersion,
+		/// No remote call configuration has been set for the destination.
+		RemoteCallNotConfigured,
+		/// Call requires more weight than the limit configured for the destination.
+		RemoteCallTooHeavy,
+		/// Message could not be sent to the destination.
+		SendFailure,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to manage the trusted locations and the remote call configurations.
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin allowed to dispatch calls on other chains with `send_remote_call`.
+		type RemoteCallOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Router sending the remote calls.
+		type XcmSender: SendXcm;
+
+		/// Location of this chain, used to deposit back the fees left on destinations.
+		type UniversalLocation: Get<InteriorMultiLocation>;
+
+		/// Number of traps listed for each origin, the oldest ones being forgotten first.
+		#[pallet::constant]
+		type MaxTrapsPerOrigin: Get<u32>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Id of a parachain.
+	pub type ParaId = u32;
+
+	impl<T: Config> Pallet<T> {
+		pub fn is_trusted_sibling(para_id: ParaId) -> bool {
+			TrustedSiblings::<T>::contains_key(para_id)
+		}
+
+		pub fn trusted_siblings() -> Vec<ParaId> {
+			TrustedSiblings::<T>::iter_keys().collect()
+		}
+
+		pub fn do_add_trusted_sibling(para_id: ParaId) -> DispatchResultWithPostInfo {
+			ensure!(!Self::is_trusted_sibling(para_id), Error::<T>::SiblingAlreadyTrusted);
+			TrustedSiblings::<T>::insert(para_id, ());
+			Self::deposit_event(Event::<T>::TrustedSiblingAdded(para_id));
+			Ok(().into())
+		}
+
+		pub fn do_remove_trusted_sibling(para_id: ParaId) -> DispatchResultWithPostInfo {
+			TrustedSiblings::<T>::take(para_id).ok_or(Error::<T>::SiblingNotTrusted)?;
+			Self::deposit_event(Event::<T>::TrustedSiblingRemoved(para_id));
+			Ok(().into())
+		}
+
+		/// Whether `teleporter` is trusted to teleport the asset at `asset`, both locations being
+		/// relative to this chain.
+		pub fn is_trusted_teleporter(asset: &MultiLocation, teleporter: &MultiLocation) -> bool {
+			TrustedTeleporters::<T>::contains_key(asset, teleporter)
+		}
+
+		/// Whether any location is trusted to teleport the asset at `asset`.
+		pub fn is_teleportable(asset: &MultiLocation) -> bool {
+			TrustedTeleporters::<T>::iter_prefix(asset).next().is_some()
+		}
+
+		pub fn do_add_trusted_teleporter(
+			asset: MultiLocation,
+			teleporter: MultiLocation,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				!Self::is_trusted_teleporter(&asset, &teleporter),
+				Error::<T>::TeleporterAlreadyTrusted
+			);
+			TrustedTeleporters::<T>::insert(asset, teleporter, ());
+			Self::deposit_event(Event::<T>::TrustedTeleporterAdded {
+				asset: asset.into(),
+				teleporter: teleporter.into(),
+			});
+			Ok(().into())
+		}
+
+		pub fn do_remove_trusted_teleporter(
+			asset: MultiLocation,
+			teleporter: MultiLocation,
+		) -> DispatchResultWithPostInfo {
+			TrustedTeleporters::<T>::take(asset, teleporter)
+				.ok_or(Error::<T>::TeleporterNotTrusted)?;
+			Self::deposit_event(Event::<T>::TrustedTeleporterRemoved {
+				asset: asset.into(),
+				teleporter: teleporter.into(),
+			});
+			Ok(().into())
+		}
+
+		pub fn remote_call_config(dest: &MultiLocation) -> Option<RemoteCallConfig> {
+			RemoteCallConfigs::<T>::get(dest)
+		}
+
+		pub fn do_set_remote_call_config(
+			dest: MultiLocation,
+			config: Option<RemoteCallConfig>,
+		) -> DispatchResultWithPostInfo {
+			RemoteCallConfigs::<T>::set(dest, config.clone());
+			Self::deposit_event(Event::<T>::RemoteCallConfigSet { dest: dest.into(), config });
+			Ok(().into())
+		}
+
+		/// Sends `call` to `dest`, to be dispatched there from the sovereign account of this chain
+		/// with at most `require_weight_at_most`, paid for as configured for `dest`.
+		pub fn do_send_remote_call(
+			dest: MultiLocation,
+			call: Vec<u8>,
+			require_weight_at_most: Weight,
+		) -> DispatchResultWithPostInfo {
+			let config =
+				Self::remote_call_config(&dest).ok_or(Error::<T>::RemoteCallNotConfigured)?;
+			ensure!(
+				require_weight_at_most.all_lte(config.weight_limit),
+				Error::<T>::RemoteCallTooHeavy
+			);
+			let message = Self::remote_call_message(&dest, config, call, require_weight_at_most)?;
+			let (message_hash, _) =
+				send_xcm::<T::XcmSender>(dest, message).map_err(|_| Error::<T>::SendFailure)?;
+			Self::deposit_event(Event::<T>::RemoteCallSent { dest: dest.into(), message_hash });
+			Ok(().into())
+		}
+
+		/// Program dispatching `call` on `dest`, the fees left being deposited back to the
+		/// sovereign account.
+		pub fn remote_call_message(
+			dest: &MultiLocation,
+			config: RemoteCallConfig,
+			call: Vec<u8>,
+			require_weight_at_most: Weight,
+		) -> Result<Xcm<()>, Error<T>> {
+			let sovereign = T::UniversalLocation::get()
+				.invert_target(dest)
+				.map_err(|_| Error::<T>::SendFailure)?;
+			let fees: MultiAsset = (config.fee_asset, config.fee_amount).into();
+			Ok(Xcm(sp_std::vec![
+				WithdrawAsset(fees.clone().into()),
+				BuyExecution { fees, weight_limit: Limited(config.weight_limit) },
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					require_weight_at_most,
+					call: call.into(),
+				},
+				RefundSurplus,
+				DepositAsset { assets: Wild(AllCounted(1)), beneficiary: sovereign },
+			]))
+		}
+
+		/// Converts `location` to the current XCM version.
+		fn normalize_location(location: VersionedMultiLocation) -> Result<MultiLocation, Error<T>> {
+			MultiLocation::try_from(location).map_err(|_| Error::<T>::UnsupportedLocationVersion)
+		}
+
+		/// Hash under which `XcmPallet` counts the traps of `assets` for `origin`.
+		pub fn trap_hash(origin: &MultiLocation, assets: &VersionedMultiAssets) -> H256 {
+			BlakeTwo256::hash_of(&(origin, assets))
+		}
+
+		/// Assets trapped for `origin`, with the hash of each trap, claimed or not, oldest first.
+		pub fn trapped_assets(origin: &MultiLocation) -> Vec<(H256, VersionedMultiAssets)> {
+			TrapHashesByOrigin::<T>::get(origin)
+				.into_iter()
+				.filter_map(|hash| Some((hash, TrappedAssetsByOrigin::<T>::get(origin, hash)?)))
+				.collect()
+		}
+
+		/// Records assets trapped by `XcmPallet` for `origin`.
+		///
+		/// Once `MaxTrapsPerOrigin` traps are listed for `origin`, the oldest one is forgotten. Its
+		/// assets can still be claimed from `XcmPallet`, they are only no longer listed.
+		pub fn note_trapped_assets(origin: MultiLocation, assets: MultiAssets) {
+			let assets = VersionedMultiAssets::from(assets);
+			let hash = Self::trap_hash(&origin, &assets);
+			TrapHashesByOrigin::<T>::mutate(origin, |hashes| {
+				if hashes.contains(&hash) {
+					return
+				}
+				if hashes.len() >= T::MaxTrapsPerOrigin::get() as usize && !hashes.is_empty() {
+					let oldest = hashes.remove(0);
+					TrappedAssetsByOrigin::<T>::remove(origin, oldest);
+				}
+				if hashes.try_push(hash).is_ok() {
+					TrappedAssetsByOrigin::<T>::insert(origin, hash, assets);
+				}
+			});
+		}
+
+		/// Forgets the assets trapped for `origin` under `hash`, once they have all been claimed.
+		pub fn forget_trapped_assets(origin: &MultiLocation, hash: H256) {
+			TrappedAssetsByOrigin::<T>::remove(origin, hash);
+			TrapHashesByOrigin::<T>::mutate_exists(origin, |hashes| {
+				if let Some(listed) = hashes {
+					listed.retain(|listed_hash| *listed_hash != hash);
+					if listed.is_empty() {
+						*hashes = None;
+					}
+				}
+			});
+		}
+
+		/// Records the assets dropped after executing XCM of `origin`.
+		pub fn note_dropped_assets(
+			origin: MultiLocation,
+			to_treasury: MultiAssets,
+			trapped: MultiAssets,
+		) {
+			Self::deposit_event(Event::<T>::AssetsDropped {
+				origin: origin.into(),
+				to_treasury: to_treasury.into(),
+				trapped: trapped.into(),
+			});
+		}
+	}
+
+	// Pallet extrinsics
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(<T as Config>::WeightInfo::add_trusted_sibling())]
+		#[pallet::call_index(0)]
+		pub fn add_trusted_sibling(
+			origin: OriginFor<T>,
+			para_id: ParaId,
+		) -> DispatchResultWithPostInfo {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Self::do_add_trusted_sibling(para_id)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::remove_trusted_sibling())]
+		#[pallet::call_index(1)]
+		pub fn remove_trusted_sibling(
+			origin: OriginFor<T>,
+			para_id: ParaId,
+		) -> DispatchResultWithPostInfo {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Self::do_remove_trusted_sibling(para_id)
+		}
+
+		/// Trusts `teleporter` to teleport the asset at `asset`, in both directions.
+		#[pallet::weight(<T as Config>::WeightInfo::add_trusted_teleporter())]
+		#[pallet::call_index(2)]
+		pub fn add_trusted_teleporter(
+			origin: OriginFor<T>,
+			asset: Box<VersionedMultiLocation>,
+			teleporter: Box<VersionedMultiLocation>,
+		) -> DispatchResultWithPostInfo {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let asset = Self::normalize_location(*asset)?;
+			let teleporter = Self::normalize_location(*teleporter)?;
+			Self::do_add_trusted_teleporter(asset, teleporter)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::remove_trusted_teleporter())]
+		#[pallet::call_index(3)]
+		pub fn remove_trusted_teleporter(
+			origin: OriginFor<T>,
+			asset: Box<VersionedMultiLocation>,
+			teleporter: Box<VersionedMultiLocation>,
+		) -> DispatchResultWithPostInfo {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let asset = Self::normalize_location(*asset)?;
+			let teleporter = Self::normalize_location(*teleporter)?;
+			Self::do_remove_trusted_teleporter(asset, teleporter)
+		}
+
+		/// Sets how calls sent to `dest` with `send_remote_call` are paid for, `fee_asset` being
+		/// given as seen from `dest`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_remote_call_config())]
+		#[pallet::call_index(4)]
+		pub fn set_remote_call_config(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			fee_asset: Box<VersionedMultiLocation>,
+			fee_amount: u128,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let dest = Self::normalize_location(*dest)?;
+			let fee_asset = Self::normalize_location(*fee_asset)?;
+			Self::do_set_remote_call_config(
+				dest,
+				Some(RemoteCallConfig { fee_asset, fee_amount, weight_limit }),
+			)
+		}
+
+		#[pallet::weight(<T as Config>::WeightInfo::set_remote_call_config())]
+		#[pallet::call_index(5)]
+		pub fn remove_remote_call_config(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+		) -> DispatchResultWithPostInfo {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let dest = Self::normalize_location(*dest)?;
+			ensure!(Self::remote_call_config(&dest).is_some(), Error::<T>::RemoteCallNotConfigured);
+			Self::do_set_remote_call_config(dest, None)
+		}
+
+		/// Dispatches the encoded `call` on `dest` from the sovereign account of this chain, with
+		/// at most `require_weight_at_most`.
+		#[pallet::weight(<T as Config>::WeightInfo::send_remote_call(call.len() as u32))]
+		#[pallet::call_index(6)]
+		pub fn send_remote_call(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			call: Vec<u8>,
+			require_weight_at_most: Weight,
+		) -> DispatchResultWithPostInfo {
+			T::RemoteCallOrigin::ensure_origin(origin)?;
+			let dest = Self::normalize_location(*dest)?;
+			Self::do_send_remote_call(dest, call, require_weight_at_most)
+		}
+	}
+}
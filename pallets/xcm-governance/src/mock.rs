@@ -0,0 +1,143 @@
+use crate as xcm_governance;
+use codec::Encode;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use std::cell::RefCell;
+use xcm::v3::prelude::*;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub const ALICE: u64 = 1;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		XcmGovernance: xcm_governance,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+thread_local! {
+	static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+/// Messages sent so far, with their destination.
+pub fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+	SENT_XCM.with(|sent| sent.borrow().clone())
+}
+
+/// Router keeping the messages sent instead of delivering them.
+pub struct TestSender;
+impl SendXcm for TestSender {
+	type Ticket = (MultiLocation, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<MultiLocation>,
+		message: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let ticket = (dest.take().ok_or(SendError::MissingArgument)?, message.take().unwrap());
+		Ok((ticket, MultiAssets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		let hash = ticket.1.using_encoded(sp_io::hashing::blake2_256);
+		SENT_XCM.with(|sent| sent.borrow_mut().push(ticket));
+		Ok(hash)
+	}
+}
+
+/// Message prepared by `TestExecutor`.
+pub struct TestPrepared;
+impl PreparedMessage for TestPrepared {
+	fn weight_of(&self) -> Weight {
+		Weight::from_parts(1_000, 0)
+	}
+}
+
+/// Executor charging fees of a single unit of the relay chain token, without executing anything.
+pub struct TestExecutor;
+impl ExecuteXcm<()> for TestExecutor {
+	type Prepared = TestPrepared;
+
+	fn prepare(_message: Xcm<()>) -> Result<Self::Prepared, Xcm<()>> {
+		Ok(TestPrepared)
+	}
+
+	fn execute(
+		_origin: impl Into<MultiLocation>,
+		pre: Self::Prepared,
+		_hash: XcmHash,
+		_weight_credit: Weight,
+	) -> Outcome {
+		xcm_governance::tracing::note_fees((Parent, 1u128).into());
+		Outcome::Complete(pre.weight_of())
+	}
+
+	fn charge_fees(_location: impl Into<MultiLocation>, _fees: MultiAssets) -> XcmResult {
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub UniversalLocation: InteriorMultiLocation =
+		X2(GlobalConsensus(NetworkId::Rococo), Parachain(2000));
+}
+
+impl xcm_governance::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type GovernanceOrigin = EnsureRoot<u64>;
+	type RemoteCallOrigin = EnsureRoot<u64>;
+	type XcmSender = TestSender;
+	type UniversalLocation = UniversalLocation;
+	type MaxTrapsPerOrigin = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
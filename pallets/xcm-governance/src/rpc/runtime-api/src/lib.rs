@@ -0,0 +1,13 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::vec::Vec;
+use xcm::VersionedMultiLocation;
+use xcm_governance::traits::TrappedAssets;
+
+sp_api::decl_runtime_apis! {
+	pub trait XcmGovernanceApi {
+		/// Returns the assets trapped for `origin` that can still be claimed. The origin can be
+		/// given in any supported XCM version.
+		fn get_trapped_assets(origin: VersionedMultiLocation) -> Vec<TrappedAssets>;
+	}
+}
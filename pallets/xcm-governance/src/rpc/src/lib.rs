@@ -0,0 +1,89 @@
+mod records;
+
+pub use records::{RpcXcmRecord, RpcXcmRecordKind, XcmTracing, XcmTracingApiServer};
+
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+use xcm::{latest::MultiAssets, VersionedMultiLocation};
+use xcm_governance::traits::TrappedAssets;
+pub use xcm_governance_runtime_api::XcmGovernanceApi as XcmGovernanceRuntimeApi;
+
+/// Trapped assets as exposed through RPC, with what is needed to claim them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTrappedAssets {
+	pub hash: H256,
+	/// Assets in the current XCM version, `None` if they cannot be converted to it.
+	pub assets: Option<MultiAssets>,
+	pub count: u32,
+}
+
+impl From<TrappedAssets> for RpcTrappedAssets {
+	fn from(trapped: TrappedAssets) -> Self {
+		Self { hash: trapped.hash, assets: trapped.assets.try_into().ok(), count: trapped.count }
+	}
+}
+
+#[rpc(client, server)]
+pub trait XcmGovernanceApi<BlockHash> {
+	/// Lists the assets trapped for `origin`, given in any supported XCM version, that can still
+	/// be claimed.
+	#[method(name = "xcmGovernance_getTrappedAssets")]
+	fn get_trapped_assets(
+		&self,
+		origin: VersionedMultiLocation,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<RpcTrappedAssets>>;
+}
+
+pub struct XcmGovernancePallet<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> XcmGovernancePallet<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block> XcmGovernanceApiServer<<Block as BlockT>::Hash> for XcmGovernancePallet<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: XcmGovernanceRuntimeApi<Block>,
+{
+	fn get_trapped_assets(
+		&self,
+		origin: VersionedMultiLocation,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<RpcTrappedAssets>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_trapped_assets(at, origin)
+			.map(|trapped| trapped.into_iter().map(Into::into).collect())
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
+
+const RUNTIME_ERROR: i32 = 1;
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Runtime error",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
@@ -0,0 +1,90 @@
+use codec::{Decode, Encode};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+use sp_core::{
+	offchain::{OffchainStorage, STORAGE_PREFIX},
+	Bytes, H256,
+};
+use xcm::latest::{MultiAssets, MultiLocation, Weight, Xcm};
+use xcm_governance::tracing::{xcm_record_key, XcmRecord, XcmRecordKind};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcXcmRecordKind {
+	Executed,
+	Sent,
+}
+
+/// Record of a message executed or sent by the chain, as exposed through RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcXcmRecord {
+	pub kind: RpcXcmRecordKind,
+	/// Origin of an executed message, destination of a sent one, `None` if it cannot be
+	/// converted to the current XCM version.
+	pub location: Option<MultiLocation>,
+	/// SCALE encoding of the versioned message.
+	pub message: Bytes,
+	/// Instructions of the message, empty if it cannot be converted to the current XCM version.
+	pub instructions: Vec<String>,
+	pub block_number: u32,
+	pub weight_used: Weight,
+	/// Fees in the current XCM version, `None` if they cannot be converted to it.
+	pub fees: Option<MultiAssets>,
+	/// Outcome of an executed message.
+	pub outcome: Option<String>,
+}
+
+impl From<XcmRecord> for RpcXcmRecord {
+	fn from(record: XcmRecord) -> Self {
+		let message = record.message.encode().into();
+		let instructions = Xcm::<()>::try_from(record.message)
+			.map(|xcm| xcm.0.iter().map(|instruction| format!("{:?}", instruction)).collect())
+			.unwrap_or_default();
+		Self {
+			kind: match record.kind {
+				XcmRecordKind::Executed => RpcXcmRecordKind::Executed,
+				XcmRecordKind::Sent => RpcXcmRecordKind::Sent,
+			},
+			location: record.location.try_into().ok(),
+			message,
+			instructions,
+			block_number: record.block_number,
+			weight_used: record.weight_used,
+			fees: record.fees.try_into().ok(),
+			outcome: record.outcome.map(|outcome| format!("{:?}", outcome)),
+		}
+	}
+}
+
+#[rpc(client, server)]
+pub trait XcmTracingApi {
+	/// Returns the record of the message with hash `message_hash` executed or sent by the chain,
+	/// if the node indexes them, that is if it runs with `--enable-offchain-indexing true`.
+	#[method(name = "xcmTracing_getRecord")]
+	fn get_record(&self, message_hash: H256) -> RpcResult<Option<RpcXcmRecord>>;
+}
+
+/// Reads the XCM records written by the runtime to the offchain storage of the node.
+pub struct XcmTracing<S> {
+	storage: S,
+}
+
+impl<S> XcmTracing<S> {
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+}
+
+impl<S> XcmTracingApiServer for XcmTracing<S>
+where
+	S: OffchainStorage + 'static,
+{
+	fn get_record(&self, message_hash: H256) -> RpcResult<Option<RpcXcmRecord>> {
+		let key = xcm_record_key(&message_hash.into());
+		let Some(encoded) = self.storage.get(STORAGE_PREFIX, &key) else { return Ok(None) };
+		XcmRecord::decode(&mut &encoded[..])
+			.map(|record| Some(record.into()))
+			.map_err(crate::runtime_error_into_rpc_err)
+	}
+}
@@ -0,0 +1,430 @@
+use crate::{
+	adapters::{IsTrustedTeleporter, TeleportableAssets, TrustedOrigins},
+	mock::*,
+	tracing::{self, xcm_record_key, TracedXcmExecutor, TracedXcmRouter, XcmRecord, XcmRecordKind},
+	traits::RemoteCallConfig,
+	Error, Event, GenesisConfig,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Contains, ContainsPair, GenesisBuild},
+};
+use sp_core::{
+	offchain::{OffchainStorage, STORAGE_PREFIX},
+	H256,
+};
+use sp_runtime::DispatchError;
+use xcm::{
+	v3::{
+		send_xcm, ExecuteXcm,
+		Instruction::{
+			BuyExecution, ClearOrigin, DepositAsset, RefundSurplus, Transact, WithdrawAsset,
+		},
+		Junction::{AccountId32, Parachain},
+		Junctions::{X1, X2},
+		MultiAsset,
+		MultiAssetFilter::Wild,
+		MultiAssets, MultiLocation, OriginKind, Outcome, PreparedMessage, Weight,
+		WeightLimit::Limited,
+		WildMultiAsset::AllCounted,
+		Xcm, XcmHash,
+	},
+	VersionedMultiAssets, VersionedXcm,
+};
+
+fn sibling(para_id: u32) -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(para_id)))
+}
+
+#[test]
+fn governance_adds_and_removes_trusted_siblings() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XcmGovernance::add_trusted_sibling(RuntimeOrigin::root(), 2000));
+		System::assert_last_event(Event::TrustedSiblingAdded(2000).into());
+		assert!(XcmGovernance::is_trusted_sibling(2000));
+		assert_eq!(XcmGovernance::trusted_siblings(), vec![2000]);
+
+		assert_ok!(XcmGovernance::remove_trusted_sibling(RuntimeOrigin::root(), 2000));
+		System::assert_last_event(Event::TrustedSiblingRemoved(2000).into());
+		assert!(!XcmGovernance::is_trusted_sibling(2000));
+	});
+}
+
+#[test]
+fn only_governance_manages_trusted_siblings() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XcmGovernance::add_trusted_sibling(RuntimeOrigin::signed(ALICE), 2000),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(XcmGovernance::add_trusted_sibling(RuntimeOrigin::root(), 2000));
+		assert_noop!(
+			XcmGovernance::remove_trusted_sibling(RuntimeOrigin::signed(ALICE), 2000),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn trusted_siblings_are_added_and_removed_once() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XcmGovernance::remove_trusted_sibling(RuntimeOrigin::root(), 2000),
+			Error::<Test>::SiblingNotTrusted
+		);
+		assert_ok!(XcmGovernance::add_trusted_sibling(RuntimeOrigin::root(), 2000));
+		assert_noop!(
+			XcmGovernance::add_trusted_sibling(RuntimeOrigin::root(), 2000),
+			Error::<Test>::SiblingAlreadyTrusted
+		);
+	});
+}
+
+#[test]
+fn governance_adds_and_removes_trusted_teleporters() {
+	new_test_ext().execute_with(|| {
+		let asset = Box::new(MultiLocation::here().into());
+		let teleporter = Box::new(sibling(2000).into());
+		assert_noop!(
+			XcmGovernance::add_trusted_teleporter(
+				RuntimeOrigin::signed(ALICE),
+				asset.clone(),
+				teleporter.clone()
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(XcmGovernance::add_trusted_teleporter(
+			RuntimeOrigin::root(),
+			asset.clone(),
+			teleporter.clone()
+		));
+		System::assert_last_event(
+			Event::TrustedTeleporterAdded {
+				asset: MultiLocation::here().into(),
+				teleporter: sibling(2000).into(),
+			}
+			.into(),
+		);
+		assert!(XcmGovernance::is_trusted_teleporter(&MultiLocation::here(), &sibling(2000)));
+		assert_noop!(
+			XcmGovernance::add_trusted_teleporter(
+				RuntimeOrigin::root(),
+				asset.clone(),
+				teleporter.clone()
+			),
+			Error::<Test>::TeleporterAlreadyTrusted
+		);
+
+		assert_ok!(XcmGovernance::remove_trusted_teleporter(
+			RuntimeOrigin::root(),
+			asset.clone(),
+			teleporter.clone()
+		));
+		System::assert_last_event(
+			Event::TrustedTeleporterRemoved {
+				asset: MultiLocation::here().into(),
+				teleporter: sibling(2000).into(),
+			}
+			.into(),
+		);
+		assert!(!XcmGovernance::is_trusted_teleporter(&MultiLocation::here(), &sibling(2000)));
+		assert_noop!(
+			XcmGovernance::remove_trusted_teleporter(RuntimeOrigin::root(), asset, teleporter),
+			Error::<Test>::TeleporterNotTrusted
+		);
+	});
+}
+
+#[test]
+fn teleporters_are_trusted_per_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XcmGovernance::do_add_trusted_teleporter(MultiLocation::here(), sibling(2000)));
+
+		let native = MultiAsset::from((MultiLocation::here(), 1u128));
+		let other = MultiAsset::from((sibling(2000), 1u128));
+		assert!(IsTrustedTeleporter::<Test>::contains(&native, &sibling(2000)));
+		assert!(!IsTrustedTeleporter::<Test>::contains(&native, &sibling(2001)));
+		assert!(!IsTrustedTeleporter::<Test>::contains(&other, &sibling(2000)));
+
+		let origin = MultiLocation::new(0, X1(AccountId32 { network: None, id: [0; 32] }));
+		assert!(TeleportableAssets::<Test>::contains(&(origin, vec![native.clone()])));
+		assert!(!TeleportableAssets::<Test>::contains(&(origin, vec![native, other])));
+	});
+}
+
+#[test]
+fn genesis_trusts_siblings() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	GenesisBuild::<Test>::assimilate_storage(
+		&GenesisConfig { trusted_siblings: vec![2000, 2001] },
+		&mut t,
+	)
+	.unwrap();
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert!(XcmGovernance::is_trusted_sibling(2000));
+		assert!(XcmGovernance::is_trusted_sibling(2001));
+		assert!(!XcmGovernance::is_trusted_sibling(2002));
+	});
+}
+
+#[test]
+fn relay_chain_and_trusted_siblings_are_trusted_origins() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XcmGovernance::add_trusted_sibling(RuntimeOrigin::root(), 2000));
+
+		assert!(TrustedOrigins::<Test>::contains(&MultiLocation::parent()));
+		assert!(TrustedOrigins::<Test>::contains(&sibling(2000)));
+		assert!(!TrustedOrigins::<Test>::contains(&sibling(2001)));
+	});
+}
+
+#[test]
+fn accounts_and_local_locations_are_not_trusted_origins() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XcmGovernance::add_trusted_sibling(RuntimeOrigin::root(), 2000));
+
+		let account = AccountId32 { network: None, id: [1; 32] };
+		assert!(!TrustedOrigins::<Test>::contains(&MultiLocation::new(
+			1,
+			X2(Parachain(2000), account)
+		)));
+		assert!(!TrustedOrigins::<Test>::contains(&MultiLocation::new(1, X1(account))));
+		assert!(!TrustedOrigins::<Test>::contains(&MultiLocation::here()));
+		assert!(!TrustedOrigins::<Test>::contains(&MultiLocation::new(0, X1(Parachain(2000)))));
+		assert!(!TrustedOrigins::<Test>::contains(&MultiLocation::grandparent()));
+	});
+}
+
+#[test]
+fn trapped_assets_are_listed_by_origin_until_forgotten() {
+	new_test_ext().execute_with(|| {
+		let assets: MultiAssets = MultiAsset::from((MultiLocation::parent(), 1_000u128)).into();
+		XcmGovernance::note_trapped_assets(sibling(2000), assets.clone());
+		XcmGovernance::note_trapped_assets(sibling(2001), assets.clone());
+
+		let hash = XcmGovernance::trap_hash(&sibling(2000), &assets.clone().into());
+		assert_eq!(XcmGovernance::trapped_assets(&sibling(2000)), vec![(hash, assets.into())]);
+		assert!(XcmGovernance::trapped_assets(&MultiLocation::parent()).is_empty());
+
+		XcmGovernance::forget_trapped_assets(&sibling(2000), hash);
+		assert!(XcmGovernance::trapped_assets(&sibling(2000)).is_empty());
+		assert_eq!(XcmGovernance::trapped_assets(&sibling(2001)).len(), 1);
+	});
+}
+
+#[test]
+fn oldest_traps_are_forgotten_past_the_limit_of_the_origin() {
+	new_test_ext().execute_with(|| {
+		let trap = |amount: u128| -> (H256, VersionedMultiAssets) {
+			let assets: MultiAssets = MultiAsset::from((MultiLocation::parent(), amount)).into();
+			XcmGovernance::note_trapped_assets(sibling(2000), assets.clone());
+			(XcmGovernance::trap_hash(&sibling(2000), &assets.clone().into()), assets.into())
+		};
+		let first = trap(1_000);
+		let second = trap(2_000);
+		assert_eq!(trap(1_000), first);
+		assert_eq!(XcmGovernance::trapped_assets(&sibling(2000)), vec![first, second.clone()]);
+
+		let third = trap(3_000);
+		assert_eq!(XcmGovernance::trapped_assets(&sibling(2000)), vec![second.clone(), third]);
+
+		XcmGovernance::forget_trapped_assets(&sibling(2000), second.0);
+		assert_eq!(XcmGovernance::trapped_assets(&sibling(2000)).len(), 1);
+	});
+}
+
+fn configure_remote_calls(dest: MultiLocation, weight_limit: Weight) {
+	assert_ok!(XcmGovernance::set_remote_call_config(
+		RuntimeOrigin::root(),
+		Box::new(dest.into()),
+		Box::new(MultiLocation::parent().into()),
+		1_000_000,
+		weight_limit,
+	));
+}
+
+#[test]
+fn remote_calls_are_sent_as_configured() {
+	new_test_ext().execute_with(|| {
+		let weight_limit = Weight::from_parts(1_000_000_000, 64 * 1024);
+		configure_remote_calls(sibling(2001), weight_limit);
+		System::assert_last_event(
+			Event::RemoteCallConfigSet {
+				dest: sibling(2001).into(),
+				config: Some(RemoteCallConfig {
+					fee_asset: MultiLocation::parent(),
+					fee_amount: 1_000_000,
+					weight_limit,
+				}),
+			}
+			.into(),
+		);
+
+		let call_weight = Weight::from_parts(500_000_000, 1024);
+		assert_ok!(XcmGovernance::send_remote_call(
+			RuntimeOrigin::root(),
+			Box::new(sibling(2001).into()),
+			vec![1, 2, 3],
+			call_weight,
+		));
+
+		let fees = MultiAsset::from((MultiLocation::parent(), 1_000_000u128));
+		let message = Xcm(vec![
+			WithdrawAsset(fees.clone().into()),
+			BuyExecution { fees, weight_limit: Limited(weight_limit) },
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: call_weight,
+				call: vec![1, 2, 3].into(),
+			},
+			RefundSurplus,
+			// The sovereign account of this chain, as seen from its sibling.
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary: sibling(2000) },
+		]);
+		assert_eq!(sent_xcm(), vec![(sibling(2001), message)]);
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::XcmGovernance(Event::RemoteCallSent { .. })
+		)));
+	});
+}
+
+#[test]
+fn remote_calls_are_only_sent_within_configured_limits() {
+	new_test_ext().execute_with(|| {
+		let dest = Box::new(sibling(2001).into());
+		let weight_limit = Weight::from_parts(1_000_000_000, 64 * 1024);
+		assert_noop!(
+			XcmGovernance::send_remote_call(
+				RuntimeOrigin::root(),
+				dest.clone(),
+				vec![],
+				weight_limit
+			),
+			Error::<Test>::RemoteCallNotConfigured
+		);
+
+		configure_remote_calls(sibling(2001), weight_limit);
+		assert_noop!(
+			XcmGovernance::send_remote_call(
+				RuntimeOrigin::signed(ALICE),
+				dest.clone(),
+				vec![],
+				weight_limit
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmGovernance::send_remote_call(
+				RuntimeOrigin::root(),
+				dest.clone(),
+				vec![],
+				weight_limit.saturating_add(Weight::from_parts(1, 0))
+			),
+			Error::<Test>::RemoteCallTooHeavy
+		);
+
+		assert_ok!(XcmGovernance::remove_remote_call_config(RuntimeOrigin::root(), dest.clone()));
+		assert_noop!(
+			XcmGovernance::send_remote_call(RuntimeOrigin::root(), dest, vec![], weight_limit),
+			Error::<Test>::RemoteCallNotConfigured
+		);
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+fn xcm_record(ext: &mut sp_io::TestExternalities, hash: XcmHash) -> Option<XcmRecord> {
+	ext.persist_offchain_overlay();
+	let encoded = ext.offchain_db().get(STORAGE_PREFIX, &xcm_record_key(&hash))?;
+	Some(XcmRecord::decode(&mut &encoded[..]).expect("record is decodable"))
+}
+
+#[test]
+fn executed_messages_are_recorded_with_their_fees_and_outcome() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		// Fees noted before the execution are not charged to the message.
+		tracing::note_fees((MultiLocation::parent(), 5u128).into());
+		let outcome = TracedXcmExecutor::<Test, TestExecutor, ()>::execute_xcm(
+			sibling(2001),
+			Xcm(vec![ClearOrigin]),
+			[1; 32],
+			Weight::MAX,
+		);
+		assert_eq!(outcome, Outcome::Complete(Weight::from_parts(1_000, 0)));
+	});
+
+	assert_eq!(
+		xcm_record(&mut ext, [1; 32]),
+		Some(XcmRecord {
+			kind: XcmRecordKind::Executed,
+			location: sibling(2001).into(),
+			message: Xcm::<()>(vec![ClearOrigin]).into(),
+			block_number: 1,
+			weight_used: Weight::from_parts(1_000, 0),
+			fees: MultiAssets::from(MultiAsset::from((MultiLocation::parent(), 1u128))).into(),
+			outcome: Some(Outcome::Complete(Weight::from_parts(1_000, 0))),
+		})
+	);
+	assert_eq!(xcm_record(&mut ext, [2; 32]), None);
+}
+
+/// Weighs recording a message at one unit of ref time per encoded byte.
+pub struct TestRecordWeight;
+impl tracing::WeightInfo for TestRecordWeight {
+	fn record_executed(b: u32) -> Weight {
+		Weight::from_parts(b.into(), 0)
+	}
+}
+
+#[test]
+fn recording_executed_messages_is_charged_to_them() {
+	let mut ext = new_test_ext();
+	let message = Xcm::<()>(vec![ClearOrigin]);
+	let record_weight = VersionedXcm::from(message.clone()).encoded_size() as u64;
+	let weight = Weight::from_parts(1_000 + record_weight, 0);
+	ext.execute_with(|| {
+		let prepared =
+			TracedXcmExecutor::<Test, TestExecutor, TestRecordWeight>::prepare(message.clone())
+				.unwrap();
+		assert_eq!(prepared.weight_of(), weight);
+
+		let outcome = TracedXcmExecutor::<Test, TestExecutor, TestRecordWeight>::execute_xcm(
+			sibling(2001),
+			message,
+			[1; 32],
+			Weight::MAX,
+		);
+		assert_eq!(outcome, Outcome::Complete(weight));
+	});
+
+	let record = xcm_record(&mut ext, [1; 32]).expect("message is recorded");
+	assert_eq!(record.weight_used, weight);
+}
+
+#[test]
+fn sent_messages_are_recorded_once_delivered() {
+	let mut ext = new_test_ext();
+	let hash = ext.execute_with(|| {
+		let (hash, _) =
+			send_xcm::<TracedXcmRouter<Test, TestSender>>(sibling(2001), Xcm(vec![ClearOrigin]))
+				.unwrap();
+		assert_eq!(sent_xcm(), vec![(sibling(2001), Xcm(vec![ClearOrigin]))]);
+		hash
+	});
+
+	assert_eq!(
+		xcm_record(&mut ext, hash),
+		Some(XcmRecord {
+			kind: XcmRecordKind::Sent,
+			location: sibling(2001).into(),
+			message: Xcm::<()>(vec![ClearOrigin]).into(),
+			block_number: 1,
+			weight_used: Weight::zero(),
+			fees: MultiAssets::new().into(),
+			outcome: None,
+		})
+	);
+}
@@ -0,0 +1,195 @@
+//! Records of the messages executed and sent by the runtime, written to the offchain storage.
+//!
+//! Tracing does not depend on the pallet: the wrappers only need `frame_system` for the block
+//! numbers, and the fees charged to a message are kept for the time of its execution only.
+
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::PhantomData;
+use scale_info::TypeInfo;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::vec::Vec;
+use xcm::{
+	v3::{
+		ExecuteXcm, MultiAsset, MultiAssets, MultiLocation, Outcome, PreparedMessage,
+		Result as XcmResult, SendError, SendResult, SendXcm, Weight, Xcm, XcmHash,
+	},
+	VersionedMultiAssets, VersionedMultiLocation, VersionedXcm,
+};
+
+environmental::environmental!(pending_fees: Vec<MultiAsset>);
+
+/// Prefix of the keys of the XCM records in the offchain storage.
+pub const XCM_RECORD_PREFIX: &[u8] = b"xcm-governance::record::";
+
+/// Key of the record of the message with hash `message_hash` in the offchain storage.
+pub fn xcm_record_key(message_hash: &XcmHash) -> Vec<u8> {
+	[XCM_RECORD_PREFIX, &message_hash[..]].concat()
+}
+
+/// Whether a recorded message was executed or sent.
+#[derive(Encode, Decode, PartialEq, Eq, TypeInfo, Clone, Copy, Debug)]
+pub enum XcmRecordKind {
+	/// Message executed on this chain, received or local.
+	Executed,
+	/// Message sent to another chain.
+	Sent,
+}
+
+/// Record of a message executed or sent by this chain, written to the offchain storage when
+/// offchain indexing is enabled on the node.
+#[derive(Encode, Decode, PartialEq, Eq, TypeInfo, Clone, Debug)]
+pub struct XcmRecord {
+	pub kind: XcmRecordKind,
+	/// Origin of an executed message, destination of a sent one.
+	pub location: VersionedMultiLocation,
+	pub message: VersionedXcm<()>,
+	/// Block in which the message was executed or sent.
+	pub block_number: u32,
+	/// Weight used by an executed message, zero for a sent one.
+	pub weight_used: Weight,
+	/// Execution fees charged to an executed message, delivery fees of a sent one.
+	pub fees: VersionedMultiAssets,
+	/// Outcome of an executed message, `None` for a sent one.
+	pub outcome: Option<Outcome>,
+}
+
+/// Weight of recording the messages executed by `TracedXcmExecutor`, which is charged to them.
+///
+/// Recording the messages sent is weighed along with sending them, since the benchmarks send
+/// through the router of the runtime.
+pub trait WeightInfo {
+	/// Recording an executed message of `b` encoded bytes.
+	fn record_executed(b: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+	fn record_executed(_b: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Notes execution fees charged to the message being executed. Fees noted outside of an
+/// execution are not recorded.
+pub fn note_fees(fees: MultiAsset) {
+	pending_fees::with(|pending| pending.push(fees));
+}
+
+/// Writes the record of the message with hash `message_hash` to the offchain storage,
+/// replacing any record of a message with the same hash.
+pub fn note_xcm_record(message_hash: XcmHash, record: XcmRecord) {
+	sp_io::offchain_index::set(&xcm_record_key(&message_hash), &record.encode());
+}
+
+/// Number of the current block, as given in the XCM records.
+fn current_block_number<T: frame_system::Config>() -> u32 {
+	frame_system::Pallet::<T>::block_number().unique_saturated_into()
+}
+
+/// Message prepared by the executor wrapped in `TracedXcmExecutor`, along with what is recorded
+/// of it.
+pub struct TracedMessage<P> {
+	prepared: P,
+	message: VersionedXcm<()>,
+	/// Weight of recording the message.
+	record_weight: Weight,
+}
+
+impl<P: PreparedMessage> PreparedMessage for TracedMessage<P> {
+	fn weight_of(&self) -> Weight {
+		self.prepared.weight_of().saturating_add(self.record_weight)
+	}
+}
+
+/// Executes messages with `E`, recording each of them with its outcome, the weight it used and
+/// the fees noted with `note_fees` during its execution. The weight of recording a message, as
+/// given by `W`, is added to the weight of the message.
+pub struct TracedXcmExecutor<T, E, W>(PhantomData<(T, E, W)>);
+impl<T, E, W, Call> ExecuteXcm<Call> for TracedXcmExecutor<T, E, W>
+where
+	T: frame_system::Config,
+	E: ExecuteXcm<Call>,
+	W: WeightInfo,
+{
+	type Prepared = TracedMessage<E::Prepared>;
+
+	fn prepare(message: Xcm<Call>) -> Result<Self::Prepared, Xcm<Call>> {
+		let traced = VersionedXcm::from(Xcm::<()>::from(message.clone()));
+		let record_weight = W::record_executed(traced.encoded_size().unique_saturated_into());
+		E::prepare(message).map(|prepared| TracedMessage {
+			prepared,
+			message: traced,
+			record_weight,
+		})
+	}
+
+	fn execute(
+		origin: impl Into<MultiLocation>,
+		pre: Self::Prepared,
+		hash: XcmHash,
+		weight_credit: Weight,
+	) -> Outcome {
+		let origin = origin.into();
+		let mut fees = Vec::new();
+		let outcome = pending_fees::using(&mut fees, || {
+			E::execute(origin, pre.prepared, hash, weight_credit)
+		});
+		let outcome = match outcome {
+			Outcome::Complete(weight) =>
+				Outcome::Complete(weight.saturating_add(pre.record_weight)),
+			Outcome::Incomplete(weight, error) =>
+				Outcome::Incomplete(weight.saturating_add(pre.record_weight), error),
+			// Not executed, so charged nothing, recording included.
+			Outcome::Error(error) => Outcome::Error(error),
+		};
+		note_xcm_record(
+			hash,
+			XcmRecord {
+				kind: XcmRecordKind::Executed,
+				location: origin.into(),
+				message: pre.message,
+				block_number: current_block_number::<T>(),
+				weight_used: outcome.weight_used(),
+				fees: MultiAssets::from(fees).into(),
+				outcome: Some(outcome.clone()),
+			},
+		);
+		outcome
+	}
+
+	fn charge_fees(location: impl Into<MultiLocation>, fees: MultiAssets) -> XcmResult {
+		E::charge_fees(location, fees)
+	}
+}
+
+/// Sends messages with `R`, recording each of them with its delivery fees once delivered.
+pub struct TracedXcmRouter<T, R>(PhantomData<(T, R)>);
+impl<T: frame_system::Config, R: SendXcm> SendXcm for TracedXcmRouter<T, R> {
+	type Ticket = (R::Ticket, MultiLocation, VersionedXcm<()>, MultiAssets);
+
+	fn validate(
+		dest: &mut Option<MultiLocation>,
+		message: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let traced = (*dest).zip(message.clone());
+		let (ticket, price) = R::validate(dest, message)?;
+		let (dest, message) = traced.ok_or(SendError::MissingArgument)?;
+		Ok(((ticket, dest, VersionedXcm::from(message), price.clone()), price))
+	}
+
+	fn deliver((ticket, dest, message, price): Self::Ticket) -> Result<XcmHash, SendError> {
+		let hash = R::deliver(ticket)?;
+		note_xcm_record(
+			hash,
+			XcmRecord {
+				kind: XcmRecordKind::Sent,
+				location: dest.into(),
+				message,
+				block_number: current_block_number::<T>(),
+				weight_used: Weight::zero(),
+				fees: price.into(),
+				outcome: None,
+			},
+		);
+		Ok(hash)
+	}
+}
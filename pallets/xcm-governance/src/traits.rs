@@ -0,0 +1,30 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use xcm::{
+	v3::{MultiLocation, Weight},
+	VersionedMultiAssets,
+};
+
+/// Assets trapped by `XcmPallet` for an origin, which can be claimed with `claim_assets`.
+#[derive(Encode, Decode, PartialEq, Eq, TypeInfo, Clone, Debug)]
+pub struct TrappedAssets {
+	/// Hash of the origin and assets, under which `XcmPallet` counts the traps.
+	pub hash: H256,
+	pub assets: VersionedMultiAssets,
+	/// Number of times the same assets have been trapped, and so can be claimed.
+	pub count: u32,
+}
+
+/// How `send_remote_call` pays for the calls it dispatches on a destination, from the sovereign
+/// account of this chain there.
+#[derive(Encode, Decode, PartialEq, Eq, TypeInfo, Clone, Debug)]
+pub struct RemoteCallConfig {
+	/// Asset paying for execution, as seen from the destination.
+	pub fee_asset: MultiLocation,
+	/// Amount of `fee_asset` withdrawn from the sovereign account to pay for execution, what is
+	/// left being deposited back.
+	pub fee_amount: u128,
+	/// Weight bought for the whole program, which bounds the weight of the calls.
+	pub weight_limit: Weight,
+}
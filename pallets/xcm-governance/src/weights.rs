@@ -0,0 +1,114 @@
+//! Weights for `xcm_governance`
+//!
+//! Estimated from the storage accesses of each call, to be replaced by the output of
+//! `scripts/benchmark.sh`. Proof sizes count the largest encoding of each item read, or a single
+//! entry of the unbounded ones, plus the trie nodes the benchmark CLI accounts for.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{constants::RocksDbWeight, Weight}};
+use core::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn add_trusted_sibling() -> Weight;
+	fn remove_trusted_sibling() -> Weight;
+	fn add_trusted_teleporter() -> Weight;
+	fn remove_trusted_teleporter() -> Weight;
+	fn set_remote_call_config() -> Weight;
+	fn send_remote_call(c: u32, ) -> Weight;
+}
+
+/// Weight functions for `xcm_governance`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: XcmGovernance TrustedSiblings (r:1 w:1)
+	fn add_trusted_sibling() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2487))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmGovernance TrustedSiblings (r:1 w:1)
+	fn remove_trusted_sibling() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2487))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmGovernance TrustedTeleporters (r:1 w:1)
+	fn add_trusted_teleporter() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3711))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmGovernance TrustedTeleporters (r:1 w:1)
+	fn remove_trusted_teleporter() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3711))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmGovernance RemoteCallConfigs (r:0 w:1)
+	fn set_remote_call_config() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmGovernance RemoteCallConfigs (r:1 w:0)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	/// The range of component `c` is `[0, 16384]`.
+	fn send_remote_call(c: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10494))
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(c.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn add_trusted_sibling() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2487))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn remove_trusted_sibling() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2487))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn add_trusted_teleporter() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3711))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn remove_trusted_teleporter() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3711))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn set_remote_call_config() -> Weight {
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn send_remote_call(c: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10494))
+			.saturating_add(Weight::from_parts(2_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(c.into()))
+	}
+}
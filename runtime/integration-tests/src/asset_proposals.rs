@@ -0,0 +1,28 @@
+use crate::*;
+use frame_support::assert_ok;
+use node_template_runtime::{
+	xcm_config::TreasuryAccount, AssetProposalDeposit, AssetsRegistry, Balances, RuntimeOrigin,
+};
+use sp_core::Get;
+
+#[test]
+fn deposits_of_rejected_proposals_go_to_the_treasury() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let treasury = Balances::free_balance(&TreasuryAccount::get());
+		assert_ok!(AssetsRegistry::propose_asset(
+			RuntimeOrigin::signed(ALICE),
+			asset(CurrencyId::ForeignAsset(2), "ACA", 1_000, sibling_location(3000)).into(),
+		));
+		assert_eq!(Balances::reserved_balance(&ALICE), AssetProposalDeposit::get());
+
+		assert_ok!(AssetsRegistry::reject_asset_proposal(RuntimeOrigin::root(), 0));
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+		assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE - AssetProposalDeposit::get());
+		assert_eq!(
+			Balances::free_balance(&TreasuryAccount::get()),
+			treasury + AssetProposalDeposit::get()
+		);
+	});
+}
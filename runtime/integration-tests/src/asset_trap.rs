@@ -0,0 +1,192 @@
+use crate::*;
+use frame_support::assert_ok;
+use node_template_runtime::{
+	assets_registry::traits::AssetDetails, xcm_config::XcmConfig, AssetsRegistry, RuntimeCall,
+	RuntimeEvent, System, Tokens, XcmGovernance, XcmPallet,
+};
+use orml_traits::MultiCurrency;
+use xcm::{v3::prelude::*, VersionedMultiAssets};
+use xcm_executor::{traits::DropAssets, XcmExecutor};
+
+/// Receives DOT from the relay chain and pays for execution, but never deposits what is left.
+fn without_deposit(amount: Balance) -> Vec<Instruction<RuntimeCall>> {
+	let fees: MultiAsset = (Parent, amount).into();
+	vec![
+		ReserveAssetDeposited(fees.clone().into()),
+		ClearOrigin,
+		BuyExecution { fees, weight_limit: Unlimited },
+	]
+}
+
+fn receive_without_deposit(amount: Balance) -> Outcome {
+	let message = Xcm::<RuntimeCall>(without_deposit(amount));
+	XcmExecutor::<XcmConfig>::execute_xcm(Parent, message, [0; 32], Weight::MAX)
+}
+
+fn trapped_assets() -> Vec<(MultiLocation, VersionedMultiAssets)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::XcmPallet(pallet_xcm::Event::AssetsTrapped(_, origin, assets)) =>
+				Some((origin, assets)),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Assets sent to the treasury and trapped, as recorded by the `AssetsDropped` events.
+fn dropped_assets() -> Vec<(VersionedMultiAssets, VersionedMultiAssets)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::XcmGovernance(xcm_governance::Event::AssetsDropped {
+				to_treasury,
+				trapped,
+				..
+			}) => Some((to_treasury, trapped)),
+			_ => None,
+		})
+		.collect()
+}
+
+fn drop_assets(assets: MultiAssets) {
+	<XcmConfig as xcm_executor::Config>::AssetTrap::drop_assets(
+		&Parent.into(),
+		assets.into(),
+		&XcmContext::with_message_hash([0; 32]),
+	);
+}
+
+fn multi_assets(assets: Vec<MultiAsset>) -> VersionedMultiAssets {
+	MultiAssets::from(assets).into()
+}
+
+#[test]
+fn leftover_assets_are_trapped() {
+	MockNet::reset();
+
+	let amount = 1_000_000_000;
+	ParaA::execute_with(|| {
+		assert!(matches!(receive_without_deposit(amount), Outcome::Complete(_)));
+
+		let fee = dot_fee(without_deposit(amount));
+		let leftover: MultiAssets = MultiAsset::from((Parent, amount - fee)).into();
+		assert_eq!(trapped_assets(), vec![(Parent.into(), leftover.clone().into())]);
+		assert_eq!(dropped_assets(), vec![(multi_assets(vec![]), leftover.into())]);
+	});
+}
+
+#[test]
+fn leftover_assets_below_existential_deposit_go_to_the_treasury() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let fee = dot_fee(without_deposit(1));
+		let amount = fee + DOT_EXISTENTIAL_DEPOSIT - 1;
+		assert!(matches!(receive_without_deposit(amount), Outcome::Complete(_)));
+
+		assert!(trapped_assets().is_empty());
+		let leftover = vec![(Parent, DOT_EXISTENTIAL_DEPOSIT - 1).into()];
+		assert_eq!(dropped_assets(), vec![(multi_assets(leftover), multi_assets(vec![]))]);
+	});
+}
+
+#[test]
+fn native_assets_use_the_native_existential_deposit() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let dust: MultiAsset = (Here, node_template_runtime::EXISTENTIAL_DEPOSIT - 1).into();
+		drop_assets(dust.clone().into());
+		assert!(trapped_assets().is_empty());
+
+		let native: MultiAsset = (Here, node_template_runtime::EXISTENTIAL_DEPOSIT).into();
+		drop_assets(native.clone().into());
+		assert_eq!(trapped_assets(), vec![(Parent.into(), multi_assets(vec![native.clone()]))]);
+
+		assert_eq!(
+			dropped_assets(),
+			vec![
+				(multi_assets(vec![dust]), multi_assets(vec![])),
+				(multi_assets(vec![]), multi_assets(vec![native])),
+			]
+		);
+	});
+}
+
+#[test]
+fn native_dust_is_trapped_when_the_treasury_cannot_take_it() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let unregistered = AssetDetails {
+			location: None,
+			..asset(
+				CurrencyId::Native,
+				"MECH",
+				node_template_runtime::EXISTENTIAL_DEPOSIT,
+				MultiLocation::here(),
+			)
+			.into()
+		};
+		assert_ok!(AssetsRegistry::do_update_asset(CurrencyId::Native, unregistered));
+
+		let dust: MultiAsset = (Here, node_template_runtime::EXISTENTIAL_DEPOSIT - 1).into();
+		drop_assets(dust.clone().into());
+
+		assert_eq!(trapped_assets(), vec![(Parent.into(), multi_assets(vec![dust.clone()]))]);
+		assert_eq!(dropped_assets(), vec![(multi_assets(vec![]), multi_assets(vec![dust]))]);
+	});
+}
+
+#[test]
+fn unregistered_and_non_fungible_assets_are_trapped() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let unregistered: MultiAsset = ((Parent, Parachain(3000)), 1u128).into();
+		let non_fungible: MultiAsset = (Parent, Index(0)).into();
+		let assets = vec![unregistered, non_fungible];
+		drop_assets(assets.clone().into());
+
+		assert_eq!(trapped_assets(), vec![(Parent.into(), multi_assets(assets.clone()))]);
+		assert_eq!(dropped_assets(), vec![(multi_assets(vec![]), multi_assets(assets))]);
+	});
+}
+
+#[test]
+fn trapped_assets_are_listed_until_claimed() {
+	MockNet::reset();
+
+	let amount = 1_000_000_000;
+	ParaA::execute_with(|| {
+		assert!(matches!(receive_without_deposit(amount), Outcome::Complete(_)));
+		let hash = System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::XcmPallet(pallet_xcm::Event::AssetsTrapped(hash, ..)) => Some(hash),
+				_ => None,
+			})
+			.unwrap();
+
+		let fee = dot_fee(without_deposit(amount));
+		let leftover: MultiAssets = MultiAsset::from((Parent, amount - fee)).into();
+		assert_eq!(
+			XcmGovernance::trapped_assets(&Parent.into()),
+			vec![(hash, leftover.clone().into())]
+		);
+		assert_eq!(XcmPallet::asset_trap(hash), 1);
+
+		let claim = Xcm::<RuntimeCall>(vec![
+			ClaimAsset { assets: leftover.clone(), ticket: Here.into() },
+			BuyExecution { fees: leftover.get(0).unwrap().clone(), weight_limit: Unlimited },
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary: account_location(BOB) },
+		]);
+		let outcome = XcmExecutor::<XcmConfig>::execute_xcm(Parent, claim, [0; 32], Weight::MAX);
+		assert!(matches!(outcome, Outcome::Complete(_)));
+
+		assert!(Tokens::free_balance(DOT, &BOB) > 0);
+		assert_eq!(XcmPallet::asset_trap(hash), 0);
+		assert!(XcmGovernance::trapped_assets(&Parent.into()).is_empty());
+	});
+}
@@ -0,0 +1,129 @@
+use crate::*;
+use codec::Encode;
+use frame_support::assert_ok;
+use node_template_runtime::{xcm_config::XcmConfig, RuntimeCall, RuntimeOrigin, XcmGovernance};
+use xcm::v3::prelude::*;
+use xcm_executor::XcmExecutor;
+
+const UNTRUSTED_PARA_ID: u32 = 3000;
+
+fn execute(origin: MultiLocation, message: Vec<Instruction<RuntimeCall>>) -> Outcome {
+	XcmExecutor::<XcmConfig>::execute_xcm(origin, Xcm(message), [0; 32], Weight::MAX)
+}
+
+fn paid(origin: MultiLocation) -> Vec<Instruction<RuntimeCall>> {
+	let fees: MultiAsset = (origin, 1_000_000_000).into();
+	vec![
+		ReserveAssetDeposited(fees.clone().into()),
+		ClearOrigin,
+		BuyExecution { fees, weight_limit: Unlimited },
+		DepositAsset { assets: Wild(AllCounted(1)), beneficiary: account_location(BOB) },
+	]
+}
+
+fn transact(call: RuntimeCall) -> Vec<Instruction<RuntimeCall>> {
+	vec![Transact {
+		origin_kind: OriginKind::SovereignAccount,
+		require_weight_at_most: Weight::from_parts(1_000_000_000, 1024 * 1024),
+		call: call.encode().into(),
+	}]
+}
+
+fn subscribe() -> Vec<Instruction<RuntimeCall>> {
+	vec![SubscribeVersion { query_id: 0, max_response_weight: Weight::zero() }]
+}
+
+#[test]
+fn paid_execution_is_allowed_from_trusted_origins_only() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		assert!(matches!(execute(Parent.into(), paid(Parent.into())), Outcome::Complete(_)));
+
+		let untrusted = sibling_location(UNTRUSTED_PARA_ID);
+		assert_eq!(execute(untrusted, paid(untrusted)), Outcome::Error(XcmError::Barrier));
+	});
+}
+
+#[test]
+fn siblings_are_rejected_once_no_longer_trusted() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let sibling = sibling_location(PARA_B_ID);
+		assert_ok!(XcmGovernance::remove_trusted_sibling(RuntimeOrigin::root(), PARA_B_ID));
+
+		assert_eq!(execute(sibling, paid(sibling)), Outcome::Error(XcmError::Barrier));
+		assert_eq!(execute(sibling, subscribe()), Outcome::Error(XcmError::Barrier));
+	});
+}
+
+#[test]
+fn unpaid_execution_is_allowed_from_the_relay_chain_only() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		assert!(matches!(execute(Parent.into(), vec![ClearOrigin]), Outcome::Complete(_)));
+
+		for origin in [sibling_location(PARA_B_ID), sibling_location(UNTRUSTED_PARA_ID)] {
+			assert_eq!(execute(origin, vec![ClearOrigin]), Outcome::Error(XcmError::Barrier));
+		}
+	});
+}
+
+#[test]
+fn subscriptions_are_allowed_from_trusted_origins_only() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		assert!(matches!(execute(sibling_location(PARA_B_ID), subscribe()), Outcome::Complete(_)));
+		assert_eq!(
+			execute(sibling_location(UNTRUSTED_PARA_ID), subscribe()),
+			Outcome::Error(XcmError::Barrier)
+		);
+	});
+}
+
+#[test]
+fn only_safe_calls_can_be_transacted() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let remark = RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: b"hello".to_vec(),
+		});
+		assert!(matches!(execute(Parent.into(), transact(remark)), Outcome::Complete(_)));
+
+		let trust = RuntimeCall::XcmGovernance(xcm_governance::Call::add_trusted_sibling {
+			para_id: UNTRUSTED_PARA_ID,
+		});
+		assert!(matches!(
+			execute(Parent.into(), transact(trust)),
+			Outcome::Incomplete(_, XcmError::NoPermission)
+		));
+		assert!(!XcmGovernance::is_trusted_sibling(UNTRUSTED_PARA_ID));
+	});
+}
+
+#[test]
+fn transfers_to_other_chains_and_proposals_cannot_be_transacted() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let transfer = RuntimeCall::XTokens(orml_xtokens::Call::transfer {
+			currency_id: DOT,
+			amount: 1_000_000,
+			dest: Box::new(account_location(BOB).into()),
+			dest_weight_limit: Unlimited,
+		});
+		let proposal = RuntimeCall::AssetsRegistry(assets_registry::Call::propose_asset {
+			asset: asset(CurrencyId::ForeignAsset(2), "ACA", 1_000, sibling_location(3000)).into(),
+		});
+		for call in [transfer, proposal] {
+			assert!(matches!(
+				execute(Parent.into(), transact(call)),
+				Outcome::Incomplete(_, XcmError::NoPermission)
+			));
+		}
+	});
+}
@@ -0,0 +1,301 @@
+//! XCM tests of the runtime, run as two parachains of a simulated relay chain.
+//!
+//! The simulator only routes the messages of its mock chains, so the messages the runtime queues
+//! in `ParachainSystem` and `XcmpQueue` are delivered with `dispatch_outbound`, standing in for
+//! the collator and the relay chain.
+
+pub mod relay;
+
+#[cfg(test)]
+mod asset_proposals;
+#[cfg(test)]
+mod asset_trap;
+#[cfg(test)]
+mod barrier;
+#[cfg(test)]
+mod remote_call;
+#[cfg(test)]
+mod teleport;
+#[cfg(test)]
+mod trader;
+#[cfg(test)]
+mod transactor;
+#[cfg(test)]
+mod versioning;
+#[cfg(test)]
+mod xtokens;
+
+use cumulus_pallet_parachain_system::relay_state_snapshot::MessagingStateSnapshot;
+use cumulus_primitives_core::{AbridgedHrmpChannel, XcmpMessageSource};
+use frame_support::{traits::GenesisBuild, weights::Weight, BoundedVec};
+use node_template_runtime::{
+	assets_registry::{self, traits::Asset},
+	xcm_config::XcmWeigher,
+	xcm_governance, AccountId, Balance, CurrencyId, Runtime, RuntimeCall, XcmpQueue,
+};
+use sp_runtime::AccountId32;
+use xcm::v3::{
+	Instruction::{self, BuyExecution, ClearOrigin, DepositAsset, ReserveAssetDeposited},
+	Junction,
+	Junctions::{X1, X2},
+	MultiAsset,
+	MultiAssetFilter::Wild,
+	MultiLocation,
+	WeightLimit::Unlimited,
+	WildMultiAsset::AllCounted,
+	Xcm,
+};
+use xcm_executor::traits::WeightBounds;
+use xcm_simulator::{
+	decl_test_network, decl_test_parachain, decl_test_relay_chain, ParaId, TestExt, UmpSink,
+	XcmpMessageHandlerT,
+};
+
+pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId32 = AccountId32::new([2u8; 32]);
+pub const INITIAL_BALANCE: Balance = 1_000_000_000_000_000;
+
+pub const PARA_A_ID: u32 = 2000;
+pub const PARA_B_ID: u32 = 2001;
+
+pub const DOT: CurrencyId = CurrencyId::ForeignAsset(0);
+pub const DOT_EXISTENTIAL_DEPOSIT: Balance = 1_000;
+pub const DOT_UNITS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Native token of the sibling parachain, registered without an execution rate.
+pub const SIBLING_TOKEN: CurrencyId = CurrencyId::ForeignAsset(1);
+
+/// Fee paid in DOT for the execution of the given message.
+pub fn dot_fee(message: Vec<Instruction<RuntimeCall>>) -> Balance {
+	let weight = XcmWeigher::weight(&mut Xcm(message)).expect("message can be weighed");
+	DOT_UNITS_PER_SECOND * weight.ref_time() as u128 /
+		node_template_runtime::WEIGHT_REF_TIME_PER_SECOND as u128
+}
+
+/// Message received from the reserve of `fees` when they are transferred to BOB.
+pub fn reserve_transfer(fees: MultiAsset) -> Vec<Instruction<RuntimeCall>> {
+	vec![
+		ReserveAssetDeposited(fees.clone().into()),
+		ClearOrigin,
+		BuyExecution { fees, weight_limit: Unlimited },
+		DepositAsset { assets: Wild(AllCounted(1)), beneficiary: account_location(BOB) },
+	]
+}
+
+decl_test_parachain! {
+	pub struct ParaA {
+		Runtime = Runtime,
+		XcmpMessageHandler = node_template_runtime::XcmpQueue,
+		DmpMessageHandler = node_template_runtime::DmpQueue,
+		new_ext = para_ext(PARA_A_ID),
+	}
+}
+
+decl_test_parachain! {
+	pub struct ParaB {
+		Runtime = Runtime,
+		XcmpMessageHandler = node_template_runtime::XcmpQueue,
+		DmpMessageHandler = node_template_runtime::DmpQueue,
+		new_ext = para_ext(PARA_B_ID),
+	}
+}
+
+decl_test_relay_chain! {
+	pub struct Relay {
+		Runtime = relay::Runtime,
+		XcmConfig = relay::XcmConfig,
+		new_ext = relay_ext(),
+	}
+}
+
+decl_test_network! {
+	pub struct MockNet {
+		relay_chain = Relay,
+		parachains = vec![
+			(PARA_A_ID, ParaA),
+			(PARA_B_ID, ParaB),
+		],
+	}
+}
+
+pub fn account_location(account: AccountId) -> MultiLocation {
+	X1(Junction::AccountId32 { network: None, id: account.into() }).into()
+}
+
+pub fn sibling_location(para_id: u32) -> MultiLocation {
+	MultiLocation::new(1, X1(Junction::Parachain(para_id)))
+}
+
+pub fn sibling_account_location(para_id: u32, account: AccountId) -> MultiLocation {
+	MultiLocation::new(
+		1,
+		X2(
+			Junction::Parachain(para_id),
+			Junction::AccountId32 { network: None, id: account.into() },
+		),
+	)
+}
+
+fn sibling_of(para_id: u32) -> u32 {
+	if para_id == PARA_A_ID {
+		PARA_B_ID
+	} else {
+		PARA_A_ID
+	}
+}
+
+pub fn asset(
+	asset_id: CurrencyId,
+	symbol: &str,
+	existential_deposit: Balance,
+	location: MultiLocation,
+) -> Asset<CurrencyId, Balance> {
+	Asset {
+		asset_id,
+		decimals: 12,
+		name: BoundedVec::truncate_from(symbol.as_bytes().to_vec()),
+		symbol: BoundedVec::truncate_from(symbol.as_bytes().to_vec()),
+		existential_deposit,
+		location: Some(location.into()),
+		metadata: Default::default(),
+	}
+}
+
+#[frame_support::storage_alias]
+type RelevantMessagingState = StorageValue<ParachainSystem, MessagingStateSnapshot>;
+
+/// Opens outbound HRMP channels to the given parachains, as the relay chain state proof would.
+fn open_channels(para_ids: &[u32]) {
+	let channel = AbridgedHrmpChannel {
+		max_capacity: 1_000,
+		max_total_size: 1_024 * 1_024,
+		max_message_size: 64 * 1_024,
+		msg_count: 0,
+		total_size: 0,
+		mqc_head: None,
+	};
+	RelevantMessagingState::put(MessagingStateSnapshot {
+		dmq_mqc_head: Default::default(),
+		relay_dispatch_queue_size: (0, 0),
+		ingress_channels: Vec::new(),
+		egress_channels: para_ids.iter().map(|id| (ParaId::from(*id), channel.clone())).collect(),
+	});
+}
+
+/// The runtime trusting its sibling, with the native token, DOT and the native token of its
+/// sibling registered, DOT paying for XCM execution.
+pub fn para_ext(para_id: u32) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+
+	pallet_balances::GenesisConfig::<Runtime> { balances: vec![(ALICE, INITIAL_BALANCE)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	<parachain_info::GenesisConfig as GenesisBuild<Runtime>>::assimilate_storage(
+		&parachain_info::GenesisConfig { parachain_id: para_id.into() },
+		&mut t,
+	)
+	.unwrap();
+
+	<pallet_xcm::GenesisConfig as GenesisBuild<Runtime>>::assimilate_storage(
+		&pallet_xcm::GenesisConfig { safe_xcm_version: Some(xcm::latest::VERSION) },
+		&mut t,
+	)
+	.unwrap();
+
+	<xcm_governance::GenesisConfig as GenesisBuild<Runtime>>::assimilate_storage(
+		&xcm_governance::GenesisConfig { trusted_siblings: vec![sibling_of(para_id)] },
+		&mut t,
+	)
+	.unwrap();
+
+	assets_registry::GenesisConfig::<Runtime> {
+		assets: vec![
+			asset(
+				CurrencyId::Native,
+				"MECH",
+				node_template_runtime::EXISTENTIAL_DEPOSIT,
+				MultiLocation::here(),
+			),
+			asset(DOT, "DOT", DOT_EXISTENTIAL_DEPOSIT, MultiLocation::parent()),
+			asset(
+				SIBLING_TOKEN,
+				"SIB",
+				node_template_runtime::EXISTENTIAL_DEPOSIT,
+				sibling_location(sibling_of(para_id)),
+			),
+		],
+		units_per_second: vec![(DOT, DOT_UNITS_PER_SECOND)],
+		next_asset_index: 2,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		frame_system::Pallet::<Runtime>::set_block_number(1);
+		open_channels(&[sibling_of(para_id)]);
+	});
+	ext
+}
+
+pub fn relay_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<relay::Runtime>()
+		.unwrap();
+
+	pallet_balances::GenesisConfig::<relay::Runtime> {
+		balances: vec![
+			(ALICE, INITIAL_BALANCE),
+			(para_account_id(PARA_A_ID), INITIAL_BALANCE),
+			(para_account_id(PARA_B_ID), INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| frame_system::Pallet::<relay::Runtime>::set_block_number(1));
+	ext
+}
+
+/// Sovereign account of a parachain in the relay chain.
+pub fn para_account_id(para_id: u32) -> relay::AccountId {
+	use sp_runtime::traits::AccountIdConversion;
+	ParaId::from(para_id).into_account_truncating()
+}
+
+/// Sovereign account of a sibling parachain in the runtime.
+pub fn sibling_account_id(para_id: u32) -> AccountId {
+	use sp_runtime::traits::AccountIdConversion;
+	polkadot_parachain::primitives::Sibling::from(para_id).into_account_truncating()
+}
+
+#[frame_support::storage_alias]
+type PendingUpwardMessages = StorageValue<ParachainSystem, Vec<Vec<u8>>, ValueQuery>;
+
+/// Takes the upward and horizontal messages queued by the runtime.
+fn take_outbound_messages() -> (Vec<Vec<u8>>, Vec<(ParaId, Vec<u8>)>) {
+	(PendingUpwardMessages::take(), XcmpQueue::take_outbound_messages(usize::MAX))
+}
+
+/// Delivers the messages sent by a parachain to the relay chain and its sibling.
+pub fn dispatch_outbound(para_id: u32) {
+	let (upward, horizontal) = if para_id == PARA_A_ID {
+		ParaA::execute_with(take_outbound_messages)
+	} else {
+		ParaB::execute_with(take_outbound_messages)
+	};
+
+	for message in upward {
+		let _ = Relay::process_upward_message(para_id.into(), &message[..], Weight::MAX);
+	}
+	for (recipient, message) in horizontal {
+		let messages = vec![(ParaId::from(para_id), 1, &message[..])].into_iter();
+		if u32::from(recipient) == PARA_A_ID {
+			ParaA::handle_xcmp_messages(messages, Weight::MAX);
+		} else {
+			ParaB::handle_xcmp_messages(messages, Weight::MAX);
+		}
+	}
+}
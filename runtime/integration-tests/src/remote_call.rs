@@ -0,0 +1,52 @@
+use crate::*;
+use codec::Encode;
+use frame_support::assert_ok;
+use node_template_runtime::{RuntimeEvent, RuntimeOrigin, System, Tokens, XcmGovernance};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use xcm::v3::prelude::*;
+
+#[test]
+fn governance_dispatches_calls_from_the_sovereign_account_on_a_sibling() {
+	MockNet::reset();
+
+	let deposit = 1_000_000_000_000;
+	let fee_amount = 1_000_000_000;
+	let sovereign = sibling_account_id(PARA_A_ID);
+	ParaB::execute_with(|| assert_ok!(Tokens::deposit(DOT, &sovereign, deposit)));
+
+	let remark = vec![1, 2, 3];
+	ParaA::execute_with(|| {
+		assert_ok!(XcmGovernance::set_remote_call_config(
+			RuntimeOrigin::root(),
+			Box::new(sibling_location(PARA_B_ID).into()),
+			Box::new(MultiLocation::parent().into()),
+			fee_amount,
+			Weight::from_parts(2_000_000_000, 128 * 1024),
+		));
+		let call =
+			RuntimeCall::System(frame_system::Call::remark_with_event { remark: remark.clone() });
+		assert_ok!(XcmGovernance::send_remote_call(
+			RuntimeOrigin::root(),
+			Box::new(sibling_location(PARA_B_ID).into()),
+			call.encode(),
+			Weight::from_parts(1_000_000_000, 64 * 1024),
+		));
+	});
+	dispatch_outbound(PARA_A_ID);
+
+	ParaB::execute_with(|| {
+		let remarked = frame_system::Event::Remarked {
+			sender: sovereign.clone(),
+			hash: BlakeTwo256::hash(&remark),
+		};
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == RuntimeEvent::System(remarked.clone())));
+
+		// What is left of the fees is deposited back to the sovereign account.
+		let balance = Tokens::free_balance(DOT, &sovereign);
+		assert!(balance < deposit);
+		assert!(balance > deposit - fee_amount);
+	});
+}
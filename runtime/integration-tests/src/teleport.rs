@@ -0,0 +1,130 @@
+use crate::*;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+use node_template_runtime::{
+	xcm_config::{CheckAccount, TreasuryAccount, XcmConfig},
+	AssetsRegistry, Balances, RuntimeCall, RuntimeOrigin, Tokens, XcmGovernance, XcmPallet,
+};
+use orml_traits::MultiCurrency;
+use xcm::v3::prelude::*;
+use xcm_executor::XcmExecutor;
+
+/// Trusts the sibling of each parachain to teleport the native token of parachain A, which
+/// pays for execution on both sides.
+fn trust_teleports_of_para_a_token() {
+	ParaA::execute_with(|| {
+		assert_ok!(XcmGovernance::add_trusted_teleporter(
+			RuntimeOrigin::root(),
+			Box::new(MultiLocation::here().into()),
+			Box::new(sibling_location(PARA_B_ID).into()),
+		));
+		assert_ok!(AssetsRegistry::do_set_units_per_second(
+			CurrencyId::Native,
+			Some(DOT_UNITS_PER_SECOND)
+		));
+	});
+	ParaB::execute_with(|| {
+		assert_ok!(XcmGovernance::add_trusted_teleporter(
+			RuntimeOrigin::root(),
+			Box::new(sibling_location(PARA_A_ID).into()),
+			Box::new(sibling_location(PARA_A_ID).into()),
+		));
+		assert_ok!(AssetsRegistry::do_set_units_per_second(
+			SIBLING_TOKEN,
+			Some(DOT_UNITS_PER_SECOND)
+		));
+	});
+}
+
+fn teleport(
+	from: AccountId,
+	to: AccountId,
+	dest: MultiLocation,
+	asset: MultiAsset,
+) -> DispatchResult {
+	XcmPallet::limited_teleport_assets(
+		RuntimeOrigin::signed(from),
+		Box::new(dest.into()),
+		Box::new(account_location(to).into()),
+		Box::new(MultiAssets::from(asset).into()),
+		0,
+		Unlimited,
+	)
+}
+
+#[test]
+fn native_token_is_teleported_to_a_trusted_sibling_and_back() {
+	MockNet::reset();
+	trust_teleports_of_para_a_token();
+
+	let amount = 1_000_000_000_000;
+	let issuance = ParaA::execute_with(|| {
+		let issuance = Balances::total_issuance();
+		assert_ok!(teleport(ALICE, BOB, sibling_location(PARA_B_ID), (Here, amount).into()));
+		assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE - amount);
+		// What left the parachain is accounted for in the checking account.
+		assert_eq!(Balances::free_balance(&CheckAccount::get()), amount);
+		assert_eq!(Balances::total_issuance(), issuance);
+		issuance
+	});
+	dispatch_outbound(PARA_A_ID);
+
+	let back = amount / 2;
+	ParaB::execute_with(|| {
+		assert_eq!(Tokens::total_issuance(SIBLING_TOKEN), amount);
+		let fee = Tokens::free_balance(SIBLING_TOKEN, &TreasuryAccount::get());
+		assert!(fee > 0);
+		assert_eq!(Tokens::free_balance(SIBLING_TOKEN, &BOB), amount - fee);
+
+		assert_ok!(teleport(
+			BOB,
+			ALICE,
+			sibling_location(PARA_A_ID),
+			(sibling_location(PARA_A_ID), back).into()
+		));
+		assert_eq!(Tokens::total_issuance(SIBLING_TOKEN), amount - back);
+	});
+	dispatch_outbound(PARA_B_ID);
+
+	ParaA::execute_with(|| {
+		assert_eq!(Balances::free_balance(&CheckAccount::get()), amount - back);
+		assert_eq!(Balances::total_issuance(), issuance);
+		let fee = Balances::free_balance(&TreasuryAccount::get());
+		assert!(fee > 0);
+		assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE - amount + back - fee);
+	});
+}
+
+#[test]
+fn assets_without_trusted_teleporters_cannot_be_teleported() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		assert_noop!(
+			teleport(ALICE, BOB, sibling_location(PARA_B_ID), (Here, 1_000_000u128).into()),
+			pallet_xcm::Error::<Runtime>::Filtered
+		);
+	});
+}
+
+#[test]
+fn assets_are_only_teleported_to_trusted_locations() {
+	MockNet::reset();
+	trust_teleports_of_para_a_token();
+
+	ParaA::execute_with(|| {
+		// The native token is trusted to be teleported to the sibling only.
+		let message = Xcm::<RuntimeCall>(vec![
+			WithdrawAsset((Here, 1_000_000_000u128).into()),
+			InitiateTeleport { assets: Wild(AllCounted(1)), dest: Parent.into(), xcm: Xcm(vec![]) },
+		]);
+		let outcome = XcmExecutor::<XcmConfig>::execute_xcm_in_credit(
+			account_location(ALICE),
+			message,
+			[0; 32],
+			Weight::MAX,
+			Weight::MAX,
+		);
+		assert!(matches!(outcome, Outcome::Incomplete(_, XcmError::UntrustedTeleportLocation)));
+		assert_eq!(Balances::free_balance(&CheckAccount::get()), 0);
+	});
+}
@@ -0,0 +1,53 @@
+use crate::*;
+use node_template_runtime::{
+	xcm_config::{TreasuryAccount, XcmConfig},
+	RuntimeCall, Tokens,
+};
+use orml_traits::MultiCurrency;
+use xcm::v3::prelude::*;
+use xcm_executor::XcmExecutor;
+
+fn execute(origin: MultiLocation, message: Vec<Instruction<RuntimeCall>>) -> Outcome {
+	XcmExecutor::<XcmConfig>::execute_xcm(origin, Xcm(message), [0; 32], Weight::MAX)
+}
+
+#[test]
+fn execution_is_paid_in_dot_to_the_treasury() {
+	MockNet::reset();
+
+	let amount = 1_000_000_000;
+	ParaA::execute_with(|| {
+		let message = reserve_transfer((Parent, amount).into());
+		let fee = dot_fee(message.clone());
+		assert!(matches!(execute(Parent.into(), message), Outcome::Complete(_)));
+
+		assert_eq!(Tokens::free_balance(DOT, &BOB), amount - fee);
+		assert_eq!(Tokens::free_balance(DOT, &TreasuryAccount::get()), fee);
+	});
+}
+
+#[test]
+fn execution_cannot_be_paid_with_assets_without_a_rate() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let sibling = sibling_location(PARA_B_ID);
+		let outcome = execute(sibling, reserve_transfer((sibling, 1_000_000_000).into()));
+		assert!(matches!(outcome, Outcome::Incomplete(_, XcmError::TooExpensive)));
+
+		assert_eq!(Tokens::free_balance(SIBLING_TOKEN, &BOB), 0);
+	});
+}
+
+#[test]
+fn execution_cannot_be_paid_with_too_little_dot() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let fee = dot_fee(reserve_transfer((Parent, 1u128).into()));
+		let outcome = execute(Parent.into(), reserve_transfer((Parent, fee - 1).into()));
+		assert!(matches!(outcome, Outcome::Incomplete(_, XcmError::TooExpensive)));
+
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 0);
+	});
+}
@@ -0,0 +1,206 @@
+use crate::*;
+use codec::{Decode, Encode};
+use frame_support::assert_ok;
+use node_template_runtime::{
+	xcm_config::{AssetTransactors, LocationToAccountId, XcmOriginToRuntimeOrigin},
+	Balances, Tokens,
+};
+use orml_traits::MultiCurrency;
+use xcm::{v3::prelude::*, VersionedMultiLocation};
+use xcm_executor::traits::{Convert, ConvertOrigin, TransactAsset};
+use xcm_simulator::TestExt;
+
+fn context() -> XcmContext {
+	XcmContext::with_message_hash([0; 32])
+}
+
+#[test]
+fn dot_reserve_transferred_from_relay_is_deposited_in_tokens() {
+	MockNet::reset();
+
+	let amount = 1_000_000_000_000;
+	Relay::execute_with(|| {
+		assert_ok!(relay::XcmPallet::reserve_transfer_assets(
+			relay::RuntimeOrigin::signed(ALICE),
+			Box::new(Parachain(PARA_A_ID).into_versioned()),
+			Box::new(account_location(BOB).into_versioned()),
+			Box::new((Here, amount).into()),
+			0,
+		));
+		assert_eq!(
+			relay::Balances::free_balance(para_account_id(PARA_A_ID)),
+			INITIAL_BALANCE + amount
+		);
+	});
+
+	ParaA::execute_with(|| {
+		let fee = dot_fee(reserve_transfer((Parent, amount).into()));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), amount - fee);
+	});
+}
+
+#[test]
+fn native_token_is_deposited_in_balances() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		assert_ok!(AssetTransactors::deposit_asset(
+			&(Here, 1_000_000).into(),
+			&account_location(BOB),
+			&context(),
+		));
+		assert_eq!(Balances::free_balance(&BOB), 1_000_000);
+		assert_eq!(Tokens::free_balance(CurrencyId::Native, &BOB), 0);
+	});
+}
+
+#[test]
+fn registered_assets_are_withdrawn_from_tokens() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		assert_ok!(Tokens::deposit(DOT, &BOB, 1_000_000_000));
+		assert_ok!(AssetTransactors::withdraw_asset(
+			&(Parent, 400_000_000).into(),
+			&account_location(BOB),
+			None,
+		));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 600_000_000);
+	});
+}
+
+#[test]
+fn unregistered_assets_are_rejected() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let unregistered: MultiAsset = ((Parent, Parachain(3000)), 1_000_000_000).into();
+		assert_eq!(
+			AssetTransactors::deposit_asset(&unregistered, &account_location(BOB), &context()),
+			Err(XcmError::FailedToTransactAsset("AssetDoesNotExist"))
+		);
+		assert!(
+			AssetTransactors::withdraw_asset(&unregistered, &account_location(BOB), None).is_err()
+		);
+	});
+}
+
+#[test]
+fn assets_are_deposited_to_the_account_of_the_sovereign_origin() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let locations = [
+			MultiLocation::parent(),
+			sibling_location(PARA_B_ID),
+			account_location(BOB),
+			sibling_account_location(PARA_B_ID, BOB),
+		];
+		for location in locations {
+			assert_ok!(AssetTransactors::deposit_asset(
+				&(Here, 1_000_000).into(),
+				&location,
+				&context()
+			));
+			assert_ok!(AssetTransactors::deposit_asset(
+				&(Parent, 1_000_000).into(),
+				&location,
+				&context()
+			));
+
+			let origin =
+				XcmOriginToRuntimeOrigin::convert_origin(location, OriginKind::SovereignAccount)
+					.expect("location converts to a sovereign origin");
+			let account = frame_system::ensure_signed(origin).expect("origin is signed");
+			assert_eq!(Balances::free_balance(&account), 1_000_000);
+			assert_eq!(Tokens::free_balance(DOT, &account), 1_000_000);
+		}
+	});
+}
+
+#[test]
+fn siblings_and_remote_accounts_have_distinct_accounts() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let sibling = LocationToAccountId::convert_ref(sibling_location(PARA_B_ID));
+		assert_eq!(sibling, Ok(sibling_account_id(PARA_B_ID)));
+
+		let remote_bob = LocationToAccountId::convert_ref(sibling_account_location(PARA_B_ID, BOB));
+		assert!(remote_bob.is_ok());
+		assert_ne!(remote_bob, sibling);
+		assert_ne!(remote_bob, LocationToAccountId::convert_ref(account_location(BOB)));
+	});
+}
+
+/// Account of BOB on the sibling parachain B, as derived by `HashedDescription`: the blake2 256
+/// hash of `(b"SiblingChain", Compact(PARA_B_ID), (b"AccountId32", BOB).encode())` SCALE encoded.
+const REMOTE_BOB: [u8; 32] = [
+	0xc6, 0x06, 0x9d, 0x92, 0xf1, 0x90, 0xcc, 0x65, 0xbb, 0x79, 0x1e, 0xc0, 0x12, 0x1c, 0x90, 0xc8,
+	0xf6, 0x59, 0xe7, 0xc3, 0xdb, 0x84, 0x70, 0x5e, 0x99, 0xaa, 0x9c, 0x1e, 0x10, 0xaf, 0x94, 0x6d,
+];
+
+#[test]
+fn remote_accounts_have_the_same_account_for_origins_and_assets() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let location = sibling_account_location(PARA_B_ID, BOB);
+		let remote_bob = AccountId::new(REMOTE_BOB);
+		assert_eq!(LocationToAccountId::convert_ref(location), Ok(remote_bob.clone()));
+
+		let origin =
+			XcmOriginToRuntimeOrigin::convert_origin(location, OriginKind::SovereignAccount)
+				.expect("location converts to a sovereign origin");
+		assert_eq!(frame_system::ensure_signed(origin), Ok(remote_bob.clone()));
+
+		assert_ok!(AssetTransactors::deposit_asset(
+			&(Here, 1_000_000).into(),
+			&location,
+			&context()
+		));
+		assert_eq!(Balances::free_balance(&remote_bob), 1_000_000);
+	});
+}
+
+#[test]
+fn remote_accounts_do_not_depend_on_the_xcm_version() {
+	use xcm::v2::{
+		Junction as V2Junction, Junctions as V2Junctions, MultiLocation as V2MultiLocation,
+		NetworkId as V2NetworkId,
+	};
+
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let v2_location = |network| {
+			V2MultiLocation::new(
+				1,
+				V2Junctions::X2(
+					V2Junction::Parachain(PARA_B_ID),
+					V2Junction::AccountId32 { network, id: BOB.into() },
+				),
+			)
+		};
+		let v3_on_polkadot = MultiLocation::new(
+			1,
+			X2(
+				Junction::Parachain(PARA_B_ID),
+				Junction::AccountId32 { network: Some(Polkadot), id: BOB.into() },
+			),
+		);
+		let locations = [
+			VersionedMultiLocation::V2(v2_location(V2NetworkId::Any)),
+			VersionedMultiLocation::V2(v2_location(V2NetworkId::Polkadot)),
+			VersionedMultiLocation::V3(sibling_account_location(PARA_B_ID, BOB)),
+			VersionedMultiLocation::V3(v3_on_polkadot),
+		];
+		for location in locations {
+			// Through the encoding of the location, as received in a message.
+			let location = VersionedMultiLocation::decode(&mut &location.encode()[..])
+				.expect("location decodes");
+			let location = MultiLocation::try_from(location).expect("location converts to v3");
+			assert_eq!(LocationToAccountId::convert_ref(location), Ok(AccountId::new(REMOTE_BOB)));
+		}
+	});
+}
@@ -0,0 +1,55 @@
+use crate::*;
+use frame_support::assert_ok;
+use node_template_runtime::{RuntimeEvent, RuntimeOrigin, System, XcmPallet};
+use xcm::v3::prelude::*;
+
+#[test]
+fn relay_chain_learns_the_version_of_the_runtime() {
+	MockNet::reset();
+
+	Relay::execute_with(|| {
+		assert_ok!(relay::XcmPallet::force_subscribe_version_notify(
+			relay::RuntimeOrigin::root(),
+			Box::new(Parachain(PARA_A_ID).into_versioned()),
+		));
+	});
+	dispatch_outbound(PARA_A_ID);
+
+	Relay::execute_with(|| {
+		assert!(relay::System::events().iter().any(|record| record.event ==
+			relay::RuntimeEvent::XcmPallet(pallet_xcm::Event::SupportedVersionChanged(
+				Parachain(PARA_A_ID).into(),
+				xcm::latest::VERSION,
+			))));
+	});
+}
+
+#[test]
+fn runtime_learns_the_version_of_its_sibling() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		assert_ok!(XcmPallet::force_subscribe_version_notify(
+			RuntimeOrigin::root(),
+			Box::new(sibling_location(PARA_B_ID).into_versioned()),
+		));
+	});
+	dispatch_outbound(PARA_A_ID);
+
+	ParaB::execute_with(|| {
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::XcmPallet(pallet_xcm::Event::VersionNotifyStarted(location, _))
+				if location == sibling_location(PARA_A_ID)
+		)));
+	});
+	dispatch_outbound(PARA_B_ID);
+
+	ParaA::execute_with(|| {
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::XcmPallet(pallet_xcm::Event::SupportedVersionChanged(
+				sibling_location(PARA_B_ID),
+				xcm::latest::VERSION,
+			))));
+	});
+}
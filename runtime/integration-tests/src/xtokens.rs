@@ -0,0 +1,69 @@
+use crate::*;
+use frame_support::assert_ok;
+use node_template_runtime::{RuntimeOrigin, Tokens, XTokens};
+use orml_traits::MultiCurrency;
+use xcm::v3::prelude::*;
+
+#[test]
+fn dot_is_transferred_back_to_the_relay_chain() {
+	MockNet::reset();
+
+	let amount = 1_000_000_000_000;
+	let relay_account =
+		MultiLocation::new(1, X1(Junction::AccountId32 { network: None, id: BOB.into() }));
+	ParaA::execute_with(|| {
+		assert_ok!(Tokens::deposit(DOT, &ALICE, amount));
+		assert_ok!(XTokens::transfer(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			amount,
+			Box::new(relay_account.into()),
+			WeightLimit::Unlimited,
+		));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 0);
+	});
+	dispatch_outbound(PARA_A_ID);
+
+	Relay::execute_with(|| {
+		assert_eq!(relay::Balances::free_balance(&BOB), amount);
+		assert_eq!(
+			relay::Balances::free_balance(para_account_id(PARA_A_ID)),
+			INITIAL_BALANCE - amount
+		);
+	});
+}
+
+#[test]
+fn dot_is_transferred_to_a_sibling_through_the_relay_chain() {
+	MockNet::reset();
+
+	let amount = 1_000_000_000_000;
+	ParaA::execute_with(|| {
+		assert_ok!(Tokens::deposit(DOT, &ALICE, amount));
+		assert_ok!(XTokens::transfer(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			amount,
+			Box::new(sibling_account_location(PARA_B_ID, BOB).into()),
+			WeightLimit::Unlimited,
+		));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 0);
+	});
+	dispatch_outbound(PARA_A_ID);
+
+	Relay::execute_with(|| {
+		assert_eq!(
+			relay::Balances::free_balance(para_account_id(PARA_A_ID)),
+			INITIAL_BALANCE - amount
+		);
+		assert_eq!(
+			relay::Balances::free_balance(para_account_id(PARA_B_ID)),
+			INITIAL_BALANCE + amount
+		);
+	});
+
+	ParaB::execute_with(|| {
+		let fee = dot_fee(reserve_transfer((Parent, amount).into()));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), amount - fee);
+	});
+}
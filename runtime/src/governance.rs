@@ -23,6 +23,9 @@ pub type EnsureRootOrTwoThirdNativeCouncil = EitherOfDiverse<
 	EnsureProportionAtLeast<AccountId, NativeCouncilCollective, 2, 3>,
 >;
 
+pub type EnsureRootOrNativeCouncilOrTechnical =
+	EitherOfDiverse<EnsureRootOrTwoThirdNativeCouncil, EnsureRootOrHalfNativeTechnical>;
+
 impl pallet_preimage::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
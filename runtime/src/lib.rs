@@ -32,7 +32,13 @@ use serde::{Deserialize, Serialize};
 
 mod governance;
 
-mod xcm_config;
+mod location_conversion;
+
+mod migrations;
+
+pub mod weights;
+
+pub mod xcm_config;
 
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
@@ -50,7 +56,7 @@ pub use frame_support::{
 };
 use frame_support::{
 	dispatch::{MaxEncodedLen, TypeInfo},
-	traits::LockIdentifier,
+	traits::{Currency, LockIdentifier, OnUnbalanced},
 	weights::{WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial},
 	PalletId,
 };
@@ -70,6 +76,14 @@ pub use pallet_template;
 pub use pallet_legacy;
 
 pub use assets_registry;
+use assets_registry::{
+	adapters::AssetStatusFilter,
+	traits::{AllocateAssetId, Asset, AssetRegistryReader, AssetStatus, AssetsPage},
+};
+use orml_traits::{currency::MutationHooks, GetByKey, MultiCurrency};
+use xcm::{v3::MultiLocation, VersionedMultiLocation};
+pub use xcm_governance;
+use xcm_governance::traits::TrappedAssets;
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -363,10 +377,29 @@ impl pallet_legacy::Config for Runtime {
 	type WeightInfo = pallet_legacy::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const AssetProposalDeposit: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+}
+
+/// Deposits the native tokens it is given, such as slashed deposits, to the treasury account.
+pub struct DepositToTreasury;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for DepositToTreasury {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+		Balances::resolve_creating(&xcm_config::TreasuryAccount::get(), amount);
+	}
+}
+
 impl assets_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
+	type AssetIssuance = CurrencyIssuance;
+	type AssetIdAllocator = CurrencyIdAllocator;
+	type RegistryOrigin = governance::EnsureRootOrTwoThirdNativeCouncil;
+	type Currency = Balances;
+	type ProposalDeposit = AssetProposalDeposit;
+	type RejectedProposalSlash = DepositToTreasury;
+	type WeightInfo = assets_registry::weights::SubstrateWeight<Runtime>;
 }
 
 #[derive(
@@ -385,21 +418,25 @@ impl assets_registry::Config for Runtime {
 )]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum CurrencyId {
+	/// The native token of this chain.
 	#[default]
-	MECH,
-	DOT,
-	TEST,
+	Native,
+	/// An asset coming from another consensus system.
+	ForeignAsset(AssetIndex),
+	/// An asset issued in this chain.
+	LocalAsset(AssetIndex),
 }
 
-trait MultiExistentialDeposit {
-	fn existential_deposit(currency_id: CurrencyId) -> Balance;
-}
+/// Index of a registered asset, shared by foreign and local assets.
+pub type AssetIndex = u32;
 
-impl MultiExistentialDeposit for CurrencyId {
-	fn existential_deposit(currency_id: CurrencyId) -> Balance {
-		match currency_id {
-			CurrencyId::MECH => EXISTENTIAL_DEPOSIT,
-			_ => Zero::zero(),
+/// Assets located in this chain get a local id, everything else a foreign one.
+pub struct CurrencyIdAllocator;
+impl AllocateAssetId<CurrencyId> for CurrencyIdAllocator {
+	fn allocate(index: AssetIndex, location: Option<&MultiLocation>) -> CurrencyId {
+		match location {
+			Some(location) if location.parents > 0 => CurrencyId::ForeignAsset(index),
+			_ => CurrencyId::LocalAsset(index),
 		}
 	}
 }
@@ -408,13 +445,30 @@ type ReserveIdentifier = [u8; 8];
 
 type MaxLocks = ConstU32<50>;
 
-use orml_traits::parameter_type_with_key;
-parameter_type_with_key! {
-	pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
-		CurrencyId::existential_deposit(*currency_id)
-	};
+/// Issuance of every currency, native one included.
+pub struct CurrencyIssuance;
+impl GetByKey<CurrencyId, Balance> for CurrencyIssuance {
+	fn get(currency_id: &CurrencyId) -> Balance {
+		Currencies::total_issuance(*currency_id)
+	}
 }
 
+/// Token transfers and deposits are subject to the status of the asset in the registry.
+pub struct CurrencyHooks;
+impl MutationHooks<AccountId, CurrencyId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = AssetStatusFilter<Runtime>;
+	type PostDeposit = ();
+	type PreTransfer = AssetStatusFilter<Runtime>;
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
+/// Existential deposits of `orml_tokens` are the ones set in the assets registry.
+pub type ExistentialDeposits = assets_registry::adapters::RegistryExistentialDeposits<Runtime>;
+
 impl orml_tokens::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -422,7 +476,7 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyId = CurrencyId;
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
-	type CurrencyHooks = ();
+	type CurrencyHooks = CurrencyHooks;
 	type MaxLocks = MaxLocks;
 	type MaxReserves = ();
 	type ReserveIdentifier = ReserveIdentifier;
@@ -430,7 +484,7 @@ impl orml_tokens::Config for Runtime {
 }
 
 parameter_types! {
-	pub const GetNativeCurrencyId: CurrencyId = CurrencyId::MECH;
+	pub const GetNativeCurrencyId: CurrencyId = CurrencyId::Native;
 }
 
 impl orml_currencies::Config for Runtime {
@@ -474,6 +528,7 @@ construct_runtime!(
 		XcmpQueue: cumulus_pallet_xcmp_queue,
 		CumulusXcm: cumulus_pallet_xcm,
 		XTokens: orml_xtokens,
+		XcmGovernance: xcm_governance,
 	}
 );
 
@@ -500,6 +555,18 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations run on runtime upgrade.
+pub type Migrations = (
+	assets_registry::migrations::v1::MigrateToV1<Runtime, migrations::OldCurrencyId>,
+	migrations::MigrateTokensToDynamicCurrencyIds,
+	assets_registry::migrations::v2::MigrateToV2<
+		Runtime,
+		migrations::OldCurrencyId,
+		migrations::ConvertOldCurrencyId,
+		migrations::FirstFreeAssetIndex,
+	>,
+	assets_registry::migrations::v3::MigrateToV3<Runtime>,
+);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -507,6 +574,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -522,6 +590,11 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_template, TemplateModule]
 		[pallet_legacy, Legacy]
+		[assets_registry, AssetsRegistry]
+		[xcm_governance, XcmGovernance]
+		[pallet_xcm, XcmPallet]
+		[pallet_xcm_benchmarks::fungible, XcmBalances]
+		[pallet_xcm_benchmarks::generic, XcmGeneric]
 	);
 }
 
@@ -546,13 +619,37 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl assets_registry_runtime_api::AssetsRegistryApi<Block> for Runtime {
-		fn get_value() -> u32 {
-			AssetsRegistry::get_value().unwrap_or(0)
+	impl assets_registry_runtime_api::AssetsRegistryApi<Block, CurrencyId, Balance> for Runtime {
+		fn get_assets(cursor: Option<CurrencyId>, limit: u32) -> AssetsPage<CurrencyId, Balance> {
+			AssetsRegistry::get_assets(cursor, limit)
+		}
+
+		fn get_asset(asset_id: CurrencyId) -> Option<Asset<CurrencyId, Balance>> {
+			AssetsRegistry::get_asset(asset_id)
 		}
 
-		fn get_assets_names() -> Vec<Vec<u8>> {
-			AssetsRegistry::get_assets_names()
+		fn get_asset_by_location(
+			location: VersionedMultiLocation,
+		) -> Option<Asset<CurrencyId, Balance>> {
+			AssetsRegistry::get_asset_by_location(location)
+		}
+
+		fn get_asset_status(asset_id: CurrencyId) -> Option<AssetStatus> {
+			AssetsRegistry::get_asset_status(asset_id)
+		}
+	}
+
+	impl xcm_governance_runtime_api::XcmGovernanceApi<Block> for Runtime {
+		fn get_trapped_assets(origin: VersionedMultiLocation) -> Vec<TrappedAssets> {
+			let Ok(origin) = MultiLocation::try_from(origin) else { return Vec::new() };
+			// Only the traps `XcmPallet` still counts can be claimed.
+			XcmGovernance::trapped_assets(&origin)
+				.into_iter()
+				.filter_map(|(hash, assets)| {
+					let count = XcmPallet::asset_trap(hash);
+					(count > 0).then_some(TrappedAssets { hash, assets, count })
+				})
+				.collect()
 		}
 	}
 
@@ -705,6 +802,8 @@ impl_runtime_apis! {
 			use frame_support::traits::StorageInfoTrait;
 			use frame_system_benchmarking::Pallet as SystemBench;
 			use baseline::Pallet as BaselineBench;
+			use pallet_xcm_benchmarks::fungible::Pallet as XcmBalances;
+			use pallet_xcm_benchmarks::generic::Pallet as XcmGeneric;
 
 			let mut list = Vec::<BenchmarkList>::new();
 			list_benchmarks!(list, extra);
@@ -725,6 +824,106 @@ impl_runtime_apis! {
 			impl frame_system_benchmarking::Config for Runtime {}
 			impl baseline::Config for Runtime {}
 
+			use xcm::latest::prelude::*;
+			use xcm_config::{LocalCheckAccount, LocationToAccountId, TokenLocation, XcmConfig};
+			use frame_benchmarking::BenchmarkError;
+
+			parameter_types! {
+				pub CheckedAccount: Option<(AccountId, xcm_builder::MintLocation)> =
+					Some(LocalCheckAccount::get());
+				pub TrustedReserve: Option<(MultiLocation, MultiAsset)> = Some((
+					MultiLocation::parent(),
+					(MultiLocation::parent(), 1_000 * EXISTENTIAL_DEPOSIT).into(),
+				));
+			}
+
+			/// Teleporters are only trusted by governance, so the relay chain is trusted to
+			/// teleport the native token before it is benchmarked.
+			pub struct TrustedTeleporter;
+			impl frame_support::traits::Get<Option<(MultiLocation, MultiAsset)>> for TrustedTeleporter {
+				fn get() -> Option<(MultiLocation, MultiAsset)> {
+					trust_parent_teleports();
+					Some((
+						MultiLocation::parent(),
+						(TokenLocation::get(), 1_000 * EXISTENTIAL_DEPOSIT).into(),
+					))
+				}
+			}
+
+			fn trust_parent_teleports() {
+				// Fails once the relay chain is trusted already.
+				let _ = XcmGovernance::do_add_trusted_teleporter(
+					TokenLocation::get(),
+					MultiLocation::parent(),
+				);
+			}
+
+			impl pallet_xcm_benchmarks::Config for Runtime {
+				type XcmConfig = XcmConfig;
+				type AccountIdConverter = LocationToAccountId;
+				fn valid_destination() -> Result<MultiLocation, BenchmarkError> {
+					// The native token is teleported there by `initiate_teleport`.
+					trust_parent_teleports();
+					Ok(MultiLocation::parent())
+				}
+				fn worst_case_holding(_depositable_count: u32) -> MultiAssets {
+					// Only the native token can be benchmarked, as the other assets need to be
+					// registered first.
+					vec![MultiAsset {
+						id: Concrete(TokenLocation::get()),
+						fun: Fungible(1_000 * EXISTENTIAL_DEPOSIT),
+					}]
+					.into()
+				}
+			}
+
+			impl pallet_xcm_benchmarks::fungible::Config for Runtime {
+				type TransactAsset = Balances;
+				type CheckedAccount = CheckedAccount;
+				type TrustedTeleporter = TrustedTeleporter;
+				type TrustedReserve = TrustedReserve;
+				fn get_multi_asset() -> MultiAsset {
+					MultiAsset {
+						id: Concrete(TokenLocation::get()),
+						fun: Fungible(1_000 * EXISTENTIAL_DEPOSIT),
+					}
+				}
+			}
+
+			impl pallet_xcm_benchmarks::generic::Config for Runtime {
+				type RuntimeCall = RuntimeCall;
+				fn worst_case_response() -> (u64, Response) {
+					(0u64, Response::Version(Default::default()))
+				}
+				fn worst_case_asset_exchange() -> Result<(MultiAssets, MultiAssets), BenchmarkError> {
+					Err(BenchmarkError::Skip)
+				}
+				fn universal_alias() -> Result<(MultiLocation, Junction), BenchmarkError> {
+					Err(BenchmarkError::Skip)
+				}
+				fn transact_origin_and_runtime_call() -> Result<(MultiLocation, RuntimeCall), BenchmarkError> {
+					Ok((MultiLocation::parent(), frame_system::Call::remark_with_event { remark: vec![] }.into()))
+				}
+				fn subscribe_origin() -> Result<MultiLocation, BenchmarkError> {
+					Ok(MultiLocation::parent())
+				}
+				fn claimable_asset() -> Result<(MultiLocation, MultiLocation, MultiAssets), BenchmarkError> {
+					let origin = MultiLocation::parent();
+					let assets: MultiAssets = (Concrete(TokenLocation::get()), 1_000 * EXISTENTIAL_DEPOSIT).into();
+					let ticket = MultiLocation { parents: 0, interior: Here };
+					Ok((origin, ticket, assets))
+				}
+				fn unlockable_asset() -> Result<(MultiLocation, MultiLocation, MultiAsset), BenchmarkError> {
+					Err(BenchmarkError::Skip)
+				}
+				fn export_message_origin_and_destination() -> Result<(MultiLocation, NetworkId, Junctions), BenchmarkError> {
+					Err(BenchmarkError::Skip)
+				}
+			}
+
+			use pallet_xcm_benchmarks::fungible::Pallet as XcmBalances;
+			use pallet_xcm_benchmarks::generic::Pallet as XcmGeneric;
+
 			use frame_support::traits::WhitelistedStorageKeys;
 			let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
 
@@ -0,0 +1,123 @@
+//! Conversion of locations to accounts by hashing a description of the location, backported from
+//! the `location_conversion` module of later xcm-builder releases.
+//!
+//! Unlike `Account32Hash`, which hashes the SCALE encoding of the location, the description of a
+//! location does not depend on the version of XCM it is given in, so the derived account does not
+//! change when the runtime moves to a new XCM version.
+
+use codec::{Compact, Encode};
+use sp_core::blake2_256;
+use sp_std::{borrow::Borrow, marker::PhantomData, vec::Vec};
+use xcm::v3::{Junction::*, Junctions::*, MultiLocation};
+use xcm_executor::traits::Convert;
+
+/// Describes a location by a byte string, `None` if the location is not described.
+pub trait DescribeLocation {
+	fn describe_location(location: &MultiLocation) -> Option<Vec<u8>>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl DescribeLocation for Tuple {
+	fn describe_location(location: &MultiLocation) -> Option<Vec<u8>> {
+		for_tuples!( #(
+			if let Some(description) = Tuple::describe_location(location) {
+				return Some(description)
+			}
+		)* );
+		None
+	}
+}
+
+/// Describes the location itself, here, by an empty description.
+pub struct DescribeTerminus;
+impl DescribeLocation for DescribeTerminus {
+	fn describe_location(location: &MultiLocation) -> Option<Vec<u8>> {
+		match (location.parents, &location.interior) {
+			(0, Here) => Some(Vec::new()),
+			_ => None,
+		}
+	}
+}
+
+/// Describes a pallet instance by its index.
+pub struct DescribePalletTerminal;
+impl DescribeLocation for DescribePalletTerminal {
+	fn describe_location(location: &MultiLocation) -> Option<Vec<u8>> {
+		match (location.parents, &location.interior) {
+			(0, X1(PalletInstance(index))) =>
+				Some((b"Pallet", Compact::<u32>::from(*index as u32)).encode()),
+			_ => None,
+		}
+	}
+}
+
+/// Describes a 32 bytes account by its key, whatever the network given.
+pub struct DescribeAccountId32Terminal;
+impl DescribeLocation for DescribeAccountId32Terminal {
+	fn describe_location(location: &MultiLocation) -> Option<Vec<u8>> {
+		match (location.parents, &location.interior) {
+			(0, X1(AccountId32 { id, .. })) => Some((b"AccountId32", id).encode()),
+			_ => None,
+		}
+	}
+}
+
+/// Describes a 20 bytes account by its key, whatever the network given.
+pub struct DescribeAccountKey20Terminal;
+impl DescribeLocation for DescribeAccountKey20Terminal {
+	fn describe_location(location: &MultiLocation) -> Option<Vec<u8>> {
+		match (location.parents, &location.interior) {
+			(0, X1(AccountKey20 { key, .. })) => Some((b"AccountKey20", key).encode()),
+			_ => None,
+		}
+	}
+}
+
+/// Describes the locations that can be found inside a chain: the chain itself, its pallets and
+/// its accounts.
+pub type DescribeAllTerminal = (
+	DescribeTerminus,
+	DescribePalletTerminal,
+	DescribeAccountId32Terminal,
+	DescribeAccountKey20Terminal,
+);
+
+/// Describes the locations inside the relay chain, a sibling parachain or a child parachain, as
+/// described by `DescribeInterior` relatively to that chain.
+pub struct DescribeFamily<DescribeInterior>(PhantomData<DescribeInterior>);
+impl<DescribeInterior: DescribeLocation> DescribeLocation for DescribeFamily<DescribeInterior> {
+	fn describe_location(location: &MultiLocation) -> Option<Vec<u8>> {
+		match (location.parents, location.interior.first()) {
+			(0, Some(Parachain(index))) => {
+				let tail = location.interior.split_first().0;
+				let interior = DescribeInterior::describe_location(&tail.into())?;
+				Some((b"ChildChain", Compact::<u32>::from(*index), interior).encode())
+			},
+			(1, Some(Parachain(index))) => {
+				let tail = location.interior.split_first().0;
+				let interior = DescribeInterior::describe_location(&tail.into())?;
+				Some((b"SiblingChain", Compact::<u32>::from(*index), interior).encode())
+			},
+			(1, _) => {
+				let interior = DescribeInterior::describe_location(&location.interior.into())?;
+				Some((b"ParentChain", interior).encode())
+			},
+			_ => None,
+		}
+	}
+}
+
+/// Converts the locations described by `Describe` to the blake2 256 hash of their description.
+pub struct HashedDescription<AccountId, Describe>(PhantomData<(AccountId, Describe)>);
+impl<AccountId: From<[u8; 32]> + Clone, Describe: DescribeLocation>
+	Convert<MultiLocation, AccountId> for HashedDescription<AccountId, Describe>
+{
+	fn convert_ref(location: impl Borrow<MultiLocation>) -> Result<AccountId, ()> {
+		let description = Describe::describe_location(location.borrow()).ok_or(())?;
+		Ok(blake2_256(&description).into())
+	}
+
+	fn reverse_ref(_: impl Borrow<AccountId>) -> Result<MultiLocation, ()> {
+		Err(())
+	}
+}
@@ -0,0 +1,258 @@
+//! Migration of balances from the former closed set of currencies to dynamic asset ids.
+
+use super::{AccountId, Balance, CurrencyId, MaxLocks, ReserveIdentifier, Runtime};
+use frame_support::{
+	inherent::Vec,
+	pallet_prelude::*,
+	parameter_types,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use orml_tokens::{AccountData, BalanceLock, ReserveData};
+use sp_runtime::traits::Convert;
+
+/// Currency ids used before assets got their ids allocated by the registry.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OldCurrencyId {
+	MECH,
+	DOT,
+	TEST,
+}
+
+pub struct ConvertOldCurrencyId;
+impl Convert<OldCurrencyId, CurrencyId> for ConvertOldCurrencyId {
+	fn convert(old: OldCurrencyId) -> CurrencyId {
+		match old {
+			OldCurrencyId::MECH => CurrencyId::Native,
+			OldCurrencyId::DOT => CurrencyId::ForeignAsset(0),
+			OldCurrencyId::TEST => CurrencyId::LocalAsset(1),
+		}
+	}
+}
+
+parameter_types! {
+	/// First asset index not taken by the converted ids.
+	pub const FirstFreeAssetIndex: u32 = 2;
+}
+
+type MaxReserves = <Runtime as orml_tokens::Config>::MaxReserves;
+
+mod old {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type TotalIssuance = StorageMap<Tokens, Twox64Concat, OldCurrencyId, Balance>;
+
+	#[frame_support::storage_alias]
+	pub type Accounts = StorageDoubleMap<
+		Tokens,
+		Blake2_128Concat,
+		AccountId,
+		Twox64Concat,
+		OldCurrencyId,
+		AccountData<Balance>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type Locks = StorageDoubleMap<
+		Tokens,
+		Blake2_128Concat,
+		AccountId,
+		Twox64Concat,
+		OldCurrencyId,
+		BoundedVec<BalanceLock<Balance>, MaxLocks>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type Reserves = StorageDoubleMap<
+		Tokens,
+		Blake2_128Concat,
+		AccountId,
+		Twox64Concat,
+		OldCurrencyId,
+		BoundedVec<ReserveData<ReserveIdentifier, Balance>, MaxReserves>,
+	>;
+}
+
+/// Re-keys `orml_tokens` balances, locks, reserves and issuance by the new currency ids:
+/// `MECH` becomes `Native`, `DOT` becomes `ForeignAsset(0)` and `TEST` becomes `LocalAsset(1)`.
+///
+/// `MECH` and `Native` share their encoding, so their entries are left where they are and only
+/// `DOT` and `TEST` entries are moved. Every entry of the tokens pallet is read once and every
+/// moved entry written twice, all within the upgrade block, which bounds the migration to chains
+/// holding a few thousand `DOT` and `TEST` accounts.
+///
+/// Runs along with the v2 migration of the assets registry, and is skipped once the registry
+/// has been migrated.
+pub struct MigrateTokensToDynamicCurrencyIds;
+impl MigrateTokensToDynamicCurrencyIds {
+	/// Whether entries of `currency_id` are stored under a different key once converted.
+	fn is_rekeyed(currency_id: OldCurrencyId) -> bool {
+		currency_id.encode() != ConvertOldCurrencyId::convert(currency_id).encode()
+	}
+
+	/// Takes out the `entries` stored under a re-keyed currency, returning them under their new
+	/// currency id along with the number of entries read.
+	fn take_rekeyed<Value>(
+		entries: impl Iterator<Item = (AccountId, OldCurrencyId, Value)>,
+		remove: impl Fn(&AccountId, OldCurrencyId),
+	) -> (Vec<(AccountId, CurrencyId, Value)>, u64) {
+		let mut read = 0;
+		let rekeyed: Vec<_> = entries
+			.inspect(|_| read += 1)
+			.filter(|(_, currency_id, _)| Self::is_rekeyed(*currency_id))
+			.collect();
+		let rekeyed = rekeyed
+			.into_iter()
+			.map(|(who, currency_id, value)| {
+				remove(&who, currency_id);
+				(who, ConvertOldCurrencyId::convert(currency_id), value)
+			})
+			.collect();
+		(rekeyed, read)
+	}
+}
+
+impl OnRuntimeUpgrade for MigrateTokensToDynamicCurrencyIds {
+	fn on_runtime_upgrade() -> Weight {
+		let registry_version = crate::AssetsRegistry::on_chain_storage_version();
+		if registry_version >= 2 {
+			log::info!(
+				target: "runtime::migrations",
+				"skipping tokens migration, assets registry storage version is {:?}",
+				registry_version
+			);
+			return <Runtime as frame_system::Config>::DbWeight::get().reads(1)
+		}
+
+		let issuances: Vec<_> = old::TotalIssuance::iter().collect();
+		let mut reads = issuances.len() as u64 + 1;
+		let mut moved = 0u64;
+		for (currency_id, issuance) in issuances {
+			if Self::is_rekeyed(currency_id) {
+				old::TotalIssuance::remove(currency_id);
+				orml_tokens::TotalIssuance::<Runtime>::insert(
+					ConvertOldCurrencyId::convert(currency_id),
+					issuance,
+				);
+				moved += 1;
+			}
+		}
+
+		// Entries are all collected before any of them is moved, so that no iterator runs over
+		// the keys being written.
+		let (accounts, read) = Self::take_rekeyed(old::Accounts::iter(), |who, currency_id| {
+			old::Accounts::remove(who, currency_id)
+		});
+		reads += read;
+		moved += accounts.len() as u64;
+		for (who, currency_id, account) in accounts {
+			orml_tokens::Accounts::<Runtime>::insert(who, currency_id, account);
+		}
+		let (locks, read) = Self::take_rekeyed(old::Locks::iter(), |who, currency_id| {
+			old::Locks::remove(who, currency_id)
+		});
+		reads += read;
+		moved += locks.len() as u64;
+		for (who, currency_id, account_locks) in locks {
+			orml_tokens::Locks::<Runtime>::insert(who, currency_id, account_locks);
+		}
+		let (reserves, read) = Self::take_rekeyed(old::Reserves::iter(), |who, currency_id| {
+			old::Reserves::remove(who, currency_id)
+		});
+		reads += read;
+		moved += reserves.len() as u64;
+		for (who, currency_id, account_reserves) in reserves {
+			orml_tokens::Reserves::<Runtime>::insert(who, currency_id, account_reserves);
+		}
+
+		log::info!(
+			target: "runtime::migrations",
+			"read {} tokens entries and moved {} of them",
+			reads - 1,
+			moved
+		);
+
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(reads, moved * 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let issuance: Vec<(CurrencyId, Balance)> = old::TotalIssuance::iter()
+			.map(|(currency_id, issuance)| (ConvertOldCurrencyId::convert(currency_id), issuance))
+			.collect();
+		let mut balances = Vec::<(CurrencyId, u32, Balance)>::new();
+		for (_, currency_id, account) in old::Accounts::iter() {
+			let currency_id = ConvertOldCurrencyId::convert(currency_id);
+			let balance = account.free.saturating_add(account.reserved);
+			match balances.iter_mut().find(|(id, _, _)| *id == currency_id) {
+				Some((_, count, total)) => {
+					*count += 1;
+					*total = total.saturating_add(balance);
+				},
+				None => balances.push((currency_id, 1, balance)),
+			}
+		}
+		Ok((issuance, balances).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let (issuance, balances): (Vec<(CurrencyId, Balance)>, Vec<(CurrencyId, u32, Balance)>) =
+			Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		for (currency_id, total) in issuance {
+			ensure!(
+				orml_tokens::TotalIssuance::<Runtime>::get(currency_id) == total,
+				"total issuance changed in the migration"
+			);
+		}
+		for (currency_id, count, total) in balances {
+			let accounts: Vec<_> = orml_tokens::Accounts::<Runtime>::iter()
+				.filter(|(_, id, _)| *id == currency_id)
+				.map(|(_, _, account)| account.free.saturating_add(account.reserved))
+				.collect();
+			ensure!(accounts.len() as u32 == count, "token accounts were lost in the migration");
+			ensure!(
+				accounts.into_iter().fold(0, Balance::saturating_add) == total,
+				"token balances changed in the migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::sp_io::TestExternalities;
+
+	#[test]
+	fn tokens_are_moved_to_the_new_currency_ids() {
+		TestExternalities::default().execute_with(|| {
+			let alice = AccountId::new([1; 32]);
+			let account = |free| AccountData { free, reserved: 0, frozen: 0 };
+			old::TotalIssuance::insert(OldCurrencyId::MECH, 100);
+			old::TotalIssuance::insert(OldCurrencyId::DOT, 30);
+			old::Accounts::insert(&alice, OldCurrencyId::MECH, account(100));
+			old::Accounts::insert(&alice, OldCurrencyId::DOT, account(30));
+
+			let weight = MigrateTokensToDynamicCurrencyIds::on_runtime_upgrade();
+
+			// Only the `DOT` entries are moved, `MECH` entries already being under `Native`.
+			assert_eq!(
+				weight,
+				<Runtime as frame_system::Config>::DbWeight::get().reads_writes(5, 4)
+			);
+			assert_eq!(orml_tokens::TotalIssuance::<Runtime>::get(CurrencyId::Native), 100);
+			assert_eq!(orml_tokens::TotalIssuance::<Runtime>::get(CurrencyId::ForeignAsset(0)), 30);
+			assert_eq!(
+				orml_tokens::Accounts::<Runtime>::get(&alice, CurrencyId::ForeignAsset(0)),
+				account(30)
+			);
+			assert_eq!(
+				orml_tokens::Accounts::<Runtime>::get(&alice, CurrencyId::Native),
+				account(100)
+			);
+			assert_eq!(orml_tokens::Accounts::<Runtime>::iter().count(), 2);
+		});
+	}
+}
@@ -0,0 +1,42 @@
+//! Weights for `CustomAssetTrap`
+//!
+//! NOT BENCHMARKED: dropping assets has no benchmark, so these weights are estimated by hand from
+//! the storage accesses of each step, and are no measurement. Proof sizes count the largest
+//! encoding of each item read.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `CustomAssetTrap`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo<T> {
+	/// Storage: AssetsRegistry LocationToAssetId (r:1 w:0)
+	/// Storage: AssetsRegistry ExistentialDeposits (r:1 w:0)
+	pub fn look_up_asset() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5595))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: AssetsRegistry LocationToAssetId (r:1 w:0)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	pub fn take_revenue() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8179))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: XcmPallet AssetTraps (r:1 w:1)
+	/// Storage: XcmGovernance TrapHashesByOrigin (r:1 w:1)
+	/// Storage: XcmGovernance TrappedAssetsByOrigin (r:0 w:2)
+	pub fn trap_assets() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6629))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+}
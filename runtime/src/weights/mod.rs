@@ -0,0 +1,6 @@
+//! Weights of the pallets configured in this runtime, and of the XCM instructions it executes.
+
+pub mod asset_trap;
+pub mod pallet_xcm;
+pub mod xcm;
+pub mod xcm_tracing;
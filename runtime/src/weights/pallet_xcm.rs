@@ -0,0 +1,143 @@
+//! Weights for `pallet_xcm`
+//!
+//! NOT BENCHMARKED: these weights are estimated by hand from the storage accesses of each
+//! call, and are no measurement. They only follow the layout of the benchmark CLI so that
+//! `scripts/benchmark.sh` can overwrite them, which has to be done before the runtime is deployed.
+//! Proof sizes count the largest encoding of each item read, or a single entry of the unbounded
+//! ones.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_xcm`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn send() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6765))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	fn teleport_assets() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 499))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: ParachainInfo ParachainId (r:1 w:0)
+	fn reserve_transfer_assets() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 499))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Local execution is filtered out, so the benchmark is overridden with `Weight::MAX`, which
+	/// `scripts/benchmark.sh` replaces with this weight. The call fails once the message is decoded
+	/// and its origin checked, without accessing storage, and `pallet_xcm` charges the weight of
+	/// the message on top of this.
+	fn execute() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+	}
+	/// Storage: XcmPallet SupportedVersion (r:0 w:1)
+	fn force_xcm_version() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmPallet SafeXcmVersion (r:0 w:1)
+	fn force_default_xcm_version() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmPallet VersionNotifiers (r:1 w:1)
+	/// Storage: XcmPallet QueryCounter (r:1 w:1)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	/// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	/// Storage: XcmPallet Queries (r:0 w:1)
+	fn force_subscribe_version_notify() -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8925))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: XcmPallet VersionNotifiers (r:1 w:1)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	/// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	/// Storage: XcmPallet Queries (r:0 w:1)
+	fn force_unsubscribe_version_notify() -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8422))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: XcmPallet SupportedVersion (r:4 w:2)
+	fn migrate_supported_version() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 12440))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: XcmPallet VersionNotifiers (r:4 w:2)
+	fn migrate_version_notifiers() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 12456))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: XcmPallet VersionNotifyTargets (r:5 w:0)
+	fn already_notified_target() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 15680))
+			.saturating_add(T::DbWeight::get().reads(5))
+	}
+	/// Storage: XcmPallet VersionNotifyTargets (r:2 w:1)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	/// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	fn notify_current_targets() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 11580))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: XcmPallet VersionNotifyTargets (r:3 w:0)
+	fn notify_target_migration_fail() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9408))
+			.saturating_add(T::DbWeight::get().reads(3))
+	}
+	/// Storage: XcmPallet VersionNotifyTargets (r:4 w:2)
+	fn migrate_version_notify_targets() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 12544))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: XcmPallet VersionNotifyTargets (r:4 w:2)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	/// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	fn migrate_and_notify_old_targets() -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 17852))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+}
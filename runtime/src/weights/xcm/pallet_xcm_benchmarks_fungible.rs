@@ -0,0 +1,88 @@
+//! Weights for `pallet_xcm_benchmarks::fungible`
+//!
+//! NOT BENCHMARKED: these weights are estimated by hand from the storage accesses of each
+//! instruction, and are no measurement. They only follow the layout of the benchmark CLI so that
+//! `scripts/benchmark.sh` can overwrite them, which has to be done before the runtime is deployed.
+//! Proof sizes count the largest encoding of each item read, or a single entry of the unbounded
+//! ones.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_xcm_benchmarks::fungible`, weighing a single asset.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo<T> {
+	/// Storage: System Account (r:1 w:1)
+	pub(crate) fn withdraw_asset() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2603))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: System Account (r:2 w:2)
+	pub(crate) fn transfer_asset() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5206))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn transfer_reserve_asset() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 11971))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	pub(crate) fn reserve_asset_deposited() -> Weight {
+		Weight::from_parts(4_000_000, 0)
+	}
+	/// Storage: XcmGovernance TrustedTeleporters (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	pub(crate) fn receive_teleported_asset() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6314))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: System Account (r:1 w:1)
+	pub(crate) fn deposit_asset() -> Weight {
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2603))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn deposit_reserve_asset() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9368))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: XcmGovernance TrustedTeleporters (r:1 w:0)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn initiate_teleport() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 13079))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -0,0 +1,167 @@
+//! Weights for `pallet_xcm_benchmarks::generic`
+//!
+//! NOT BENCHMARKED: these weights are estimated by hand from the storage accesses of each
+//! instruction, and are no measurement. They only follow the layout of the benchmark CLI so that
+//! `scripts/benchmark.sh` can overwrite them, which has to be done before the runtime is deployed.
+//! Proof sizes count the largest encoding of each item read, or a single entry of the unbounded
+//! ones.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_xcm_benchmarks::generic`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo<T> {
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn report_holding() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6765))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn buy_execution() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+	}
+	/// Storage: XcmPallet Queries (r:1 w:1)
+	pub(crate) fn query_response() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3715))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	pub(crate) fn transact() -> Weight {
+		Weight::from_parts(15_000_000, 0)
+	}
+	pub(crate) fn refund_surplus() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn set_error_handler() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn set_appendix() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn clear_error() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn descend_origin() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn clear_origin() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn report_error() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6765))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: XcmPallet AssetTraps (r:1 w:1)
+	/// Storage: XcmGovernance TrappedAssetsByOrigin (r:0 w:1)
+	/// Storage: XcmGovernance TrapHashesByOrigin (r:1 w:1)
+	pub(crate) fn claim_asset() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6629))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	pub(crate) fn trap() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	/// Storage: XcmPallet VersionNotifyTargets (r:1 w:1)
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn subscribe_version() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9901))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: XcmPallet VersionNotifyTargets (r:0 w:1)
+	pub(crate) fn unsubscribe_version() -> Weight {
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn initiate_reserve_withdraw() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6765))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn burn_asset() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+	}
+	pub(crate) fn expect_asset() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn expect_origin() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn expect_error() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn expect_transact_status() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn query_pallet() -> Weight {
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6765))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn expect_pallet() -> Weight {
+		Weight::from_parts(4_000_000, 0)
+	}
+	/// Storage: XcmPallet SupportedVersion (r:1 w:0)
+	/// Storage: XcmPallet VersionDiscoveryQueue (r:1 w:1)
+	/// Storage: XcmPallet SafeXcmVersion (r:1 w:0)
+	/// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	/// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	pub(crate) fn report_transact_status() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6765))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	pub(crate) fn clear_transact_status() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn set_topic() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn clear_topic() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn set_fees_mode() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+	pub(crate) fn unpaid_execution() -> Weight {
+		Weight::from_parts(3_000_000, 0)
+	}
+}
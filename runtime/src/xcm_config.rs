@@ -1,9 +1,15 @@
 use super::{
-	parameter_types, AccountId, AssetsRegistry, Balance, Balances, ConstU32, ParachainInfo,
-	ParachainSystem, RuntimeCall, Tokens, XcmPallet, XcmpQueue,
+	parameter_types, AccountId, AssetsRegistry, Balance, Balances, ConstU32, Currencies,
+	ParachainInfo, ParachainSystem, RuntimeCall, XcmGovernance, XcmPallet, XcmpQueue,
+	EXISTENTIAL_DEPOSIT,
 };
 use crate::{
-	governance::{EnsureRootOrHalfNativeTechnical, EnsureRootOrTwoThirdNativeCouncil},
+	governance::{
+		EnsureRootOrHalfNativeTechnical, EnsureRootOrNativeCouncilOrTechnical,
+		EnsureRootOrTwoThirdNativeCouncil,
+	},
+	location_conversion::{DescribeAllTerminal, DescribeFamily, HashedDescription},
+	weights::{self, asset_trap::WeightInfo as AssetTrapWeight},
 	CurrencyId, ExistentialDeposits, GetNativeCurrencyId, Runtime, RuntimeEvent, RuntimeOrigin,
 	TreasuryPalletId,
 };
@@ -12,67 +18,126 @@ use orml_traits::MultiCurrency;
 // use cumulus_pallet_xcm::Origin as CumulusXcmOrigin;
 use cumulus_primitives_utility::ParentAsUmp;
 use frame_support::{
-	dispatch::Weight,
+	dispatch::{DispatchResult, Weight},
 	inherent::Vec,
 	match_types,
-	traits::{Everything, Get, Nothing},
+	traits::{Contains, ContainsPair, Everything, Get, Nothing},
 };
 use orml_traits::{
 	location::{AbsoluteReserveProvider, RelativeReserveProvider},
-	parameter_type_with_key, GetByKey,
+	GetByKey,
+};
+use orml_xcm_support::{
+	DepositToAlternative, IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset, UnknownAsset,
 };
-use orml_xcm_support::MultiNativeAsset;
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::Sibling;
-use polkadot_primitives::Id as ParaId;
 use sp_runtime::traits::{AccountIdConversion, Convert};
 use sp_std::{marker::PhantomData, vec};
-use xcm::{
-	v3::{prelude::*, MultiLocation, Weight as XcmWeight},
-	VersionedMultiLocation,
-};
+use xcm::v3::{prelude::*, MultiLocation, Weight as XcmWeight};
 use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
-	AllowTopLevelPaidExecutionFrom, ChildParachainConvertsVia,
-	CurrencyAdapter as XcmCurrencyAdapter, EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds,
-	IsConcrete, MintLocation, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative,
-	SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
-	SovereignSignedViaLocation, TakeRevenue, TakeWeightCredit,
+	AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom,
+	CurrencyAdapter as XcmCurrencyAdapter, EnsureXcmOrigin, IsConcrete, MintLocation,
+	ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeRevenue,
+	TakeWeightCredit, WeightInfoBounds,
+};
+use xcm_executor::{
+	traits::{ClaimAssets, DropAssets, TransactAsset},
+	Assets, XcmExecutor,
 };
-use xcm_executor::{traits::DropAssets, Assets, XcmExecutor};
 
 parameter_types! {
-	pub NativeTokenExistentialDeposit: Balance = 0; // TODO: get proper ED
+	pub const NativeTokenExistentialDeposit: Balance = EXISTENTIAL_DEPOSIT;
 	pub const BaseXcmWeight: Weight = Weight::from_parts(100_000_000, 0);
 	pub const XcmMaxAssetsForTransfer: usize = 2;
 	pub const TokenLocation: MultiLocation = Here.into_location();
-	pub const ThisNetwork: NetworkId = NetworkId::Rococo;
 	pub const RelayNetwork: NetworkId = NetworkId::Rococo;
-	pub const UnitWeightCost: Weight = Weight::from_parts(200_000_000, 0);
 	pub const MaxInstructions: u32 = 100;
-	pub DotPerSecond: (AssetId, u128, u128) = (MultiLocation::parent().into(), 1, 0);
+	pub const MaxAssetsIntoHolding: u32 = 64;
 	pub RelayOrigin: cumulus_pallet_xcm::Origin = cumulus_pallet_xcm::Origin::Relay;
 	pub CheckAccount: AccountId = XcmPallet::check_account();
 	pub LocalCheckAccount: (AccountId, MintLocation) =(CheckAccount::get(), MintLocation::Local);
 	pub UniversalLocation: InteriorMultiLocation = X2(GlobalConsensus(RelayNetwork::get()), Parachain(ParachainInfo::parachain_id().into()));
+	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
 }
 
 match_types! {
-	pub type ParentOrSiblings: impl Contains<MultiLocation> = {
-		MultiLocation { parents: 1, interior: Here } |
-		MultiLocation { parents: 1, interior: X1(_) }
+	pub type ParentLocation: impl Contains<MultiLocation> = {
+		MultiLocation { parents: 1, interior: Here }
 	};
 }
 
+/// Only the relay chain and the sibling parachains trusted by governance can execute XCM here.
+pub type TrustedOrigins = xcm_governance::adapters::TrustedOrigins<Runtime>;
+
 pub type Barrier = (
-	AllowKnownQueryResponses<XcmPallet>,
-	AllowSubscriptionsFrom<ParentOrSiblings>,
-	AllowTopLevelPaidExecutionFrom<Everything>,
 	TakeWeightCredit,
+	AllowTopLevelPaidExecutionFrom<TrustedOrigins>,
+	// Messages of the relay chain are executed even when not paid for, those paying for their
+	// execution being let through by the previous barrier so that they are charged.
+	AllowUnpaidExecutionFrom<ParentLocation>,
+	AllowKnownQueryResponses<XcmPallet>,
+	AllowSubscriptionsFrom<TrustedOrigins>,
 );
 
-pub type Trader = FixedRateOfFungible<DotPerSecond, ToTreasury>;
+/// Calls that can be dispatched with `Transact`: local transfers that any account could make,
+/// nothing that requires a privileged origin.
+///
+/// Transfers to other chains and asset proposals are left out, so that other chains cannot send
+/// their sovereign funds further or lock them in proposal deposits.
+pub struct SafeCallFilter;
+impl Contains<RuntimeCall> for SafeCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::System(
+				frame_system::Call::remark { .. } | frame_system::Call::remark_with_event { .. }
+			) | RuntimeCall::Balances(
+				pallet_balances::Call::transfer { .. } |
+					pallet_balances::Call::transfer_keep_alive { .. } |
+					pallet_balances::Call::transfer_all { .. }
+			) | RuntimeCall::Tokens(
+				orml_tokens::Call::transfer { .. } |
+					orml_tokens::Call::transfer_keep_alive { .. } |
+					orml_tokens::Call::transfer_all { .. }
+			) | RuntimeCall::Currencies(
+				orml_currencies::Call::transfer { .. } |
+					orml_currencies::Call::transfer_native_currency { .. }
+			)
+		)
+	}
+}
 
+impl xcm_governance::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type GovernanceOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	// Both collectives can manage the sovereign account of the parachain on other chains.
+	type RemoteCallOrigin = EnsureRootOrNativeCouncilOrTechnical;
+	type XcmSender = XcmRouter;
+	type UniversalLocation = UniversalLocation;
+	type MaxTrapsPerOrigin = ConstU32<32>;
+	type WeightInfo = xcm_governance::weights::SubstrateWeight<Runtime>;
+}
+
+/// Weighs each instruction with the weights benchmarked in `pallet_xcm_benchmarks`.
+pub type XcmWeigher =
+	WeightInfoBounds<weights::xcm::XcmWeight<RuntimeCall>, RuntimeCall, MaxInstructions>;
+
+/// XCM execution is paid with any registered asset that has an execution rate set.
+pub type Trader = assets_registry::trader::RegistryWeightTrader<Runtime, NoteFees<ToTreasury>>;
+
+/// Executes XCM with `XcmConfig`, recording each message in the offchain storage.
+pub type TracedXcmExecutor = xcm_governance::tracing::TracedXcmExecutor<
+	Runtime,
+	XcmExecutor<XcmConfig>,
+	weights::xcm_tracing::WeightInfo<Runtime>,
+>;
+
+/// Converts a location to the account it controls here. Used for the sovereign origins, the
+/// assets deposited and withdrawn and `pallet_xcm`, so that a location has the same account in
+/// all of them.
 pub type LocationToAccountId = (
 	// The parent (Relay-chain) origin converts to the parent `AccountId`.
 	ParentIsPreset<AccountId>,
@@ -80,6 +145,9 @@ pub type LocationToAccountId = (
 	SiblingParachainConvertsVia<Sibling, AccountId>,
 	// Straight up local `AccountId32` origins just alias directly to `AccountId`.
 	AccountId32Aliases<RelayNetwork, AccountId>,
+	// Accounts and pallets of the relay chain and of other parachains convert to the hash of a
+	// description of their location, which does not depend on the XCM version.
+	HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
 );
 
 /// This is used to convert XCM origin (MultiLocation) to local RuntimeOrigin.
@@ -106,12 +174,12 @@ pub type XcmOriginToRuntimeOrigin = (
 
 impl cumulus_pallet_xcm::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmExecutor = TracedXcmExecutor;
 }
 
 impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmExecutor = TracedXcmExecutor;
 	type VersionWrapper = XcmPallet;
 	type ChannelInfo = ParachainSystem;
 	type ControllerOriginConverter = XcmOriginToRuntimeOrigin;
@@ -123,7 +191,7 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 
 impl cumulus_pallet_dmp_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmExecutor = TracedXcmExecutor;
 	type ExecuteOverweightOrigin = EnsureRootOrTwoThirdNativeCouncil;
 }
 
@@ -131,52 +199,80 @@ parameter_types! {
 	pub const ThisLocal: MultiLocation = MultiLocation { parents: 0, interior: Here };
 }
 
-parameter_type_with_key! {
-	pub ParachainMinFee: |location: MultiLocation| -> Option<Balance> {
-		// TODO: implement properly
-		#[allow(clippy::match_ref_pats)] // false positive
-		#[allow(clippy::match_single_binding)]
-		let parents = location.parents;
-		let interior = location.first_interior();
-
-		let _location = VersionedMultiLocation::V3(*location);
-		if let Some(Parachain(_id)) = interior {
-			// if let Some(amount) = AssetsRegistry::min_xcm_fee(*id, location.into()) {
-			// 	return Some(amount)
-			// }
-			return Some(0)
-		}
-
-		match (parents, interior) {
+/// Minimum fees of outgoing transfers, by the reserve of their fees: a fixed one for the relay
+/// chain, and the ones set in the assets registry for other reserves.
+pub struct ParachainMinFee;
+impl GetByKey<MultiLocation, Option<Balance>> for ParachainMinFee {
+	fn get(location: &MultiLocation) -> Option<Balance> {
+		match (location.parents, location.first_interior()) {
 			(1, None) => Some(400_000),
-			_ => None,
+			_ => assets_registry::adapters::RegistryMinXcmFees::<Runtime>::get(location),
 		}
-	};
+	}
 }
 
 pub struct CurrencyIdConvert;
 impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
 	fn convert(asset_id: CurrencyId) -> Option<MultiLocation> {
-		AssetsRegistry::get_location_by_asset(asset_id)
+		<AssetsRegistry as AssetRegistryReader<_, Balance>>::get_location_by_asset(asset_id)
 	}
 }
 impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
 	fn convert(location: MultiLocation) -> Option<CurrencyId> {
-		AssetsRegistry::get_asset_id_by_location(location)
+		<AssetsRegistry as AssetRegistryReader<_, Balance>>::get_asset_id_by_location(&location)
+	}
+}
+impl Convert<MultiAsset, Option<CurrencyId>> for CurrencyIdConvert {
+	fn convert(asset: MultiAsset) -> Option<CurrencyId> {
+		match asset.id {
+			Concrete(location) => <Self as Convert<MultiLocation, _>>::convert(location),
+			Abstract(_) => None,
+		}
 	}
 }
 
+/// Fallible version of `TakeRevenue`, for callers that can do something else with the revenue
+/// when it cannot be taken.
+pub trait TryTakeRevenue {
+	fn try_take_revenue(revenue: MultiAsset) -> DispatchResult;
+}
+
 pub struct ToTreasury;
+impl TryTakeRevenue for ToTreasury {
+	fn try_take_revenue(revenue: MultiAsset) -> DispatchResult {
+		let MultiAsset { id: Concrete(location), fun: Fungible(amount) } = revenue else {
+			return Err(assets_registry::Error::<Runtime>::AssetDoesNotExist.into())
+		};
+		let currency_id = CurrencyIdConvert::convert(location)
+			.ok_or(assets_registry::Error::<Runtime>::AssetDoesNotExist)?;
+		Currencies::deposit(currency_id, &TreasuryAccount::get(), amount)
+	}
+}
 impl TakeRevenue for ToTreasury {
 	fn take_revenue(revenue: MultiAsset) {
-		let treasury_pallet = TreasuryPalletId::get();
-		let treasury_account: AccountId = treasury_pallet.into_account_truncating();
+		let _ = Self::try_take_revenue(revenue);
+	}
+}
 
-		if let MultiAsset { id: Concrete(location), fun: Fungible(amount) } = revenue {
-			if let Some(currency_id) = CurrencyIdConvert::convert(location) {
-				let _ = Tokens::deposit(currency_id, &treasury_account, amount);
-			}
-		}
+/// Notes execution fees for the XCM records before handing them to `R`.
+pub struct NoteFees<R>(PhantomData<R>);
+impl<R: TakeRevenue> TakeRevenue for NoteFees<R> {
+	fn take_revenue(revenue: MultiAsset) {
+		xcm_governance::tracing::note_fees(revenue.clone());
+		R::take_revenue(revenue);
+	}
+}
+
+/// Assets that are not in the registry cannot be deposited nor withdrawn, so that they end up
+/// trapped instead of being silently lost.
+pub struct UnregisteredAssets;
+impl UnknownAsset for UnregisteredAssets {
+	fn deposit(_asset: &MultiAsset, _to: &MultiLocation) -> DispatchResult {
+		Err(assets_registry::Error::<Runtime>::AssetDoesNotExist.into())
+	}
+
+	fn withdraw(_asset: &MultiAsset, _from: &MultiLocation) -> DispatchResult {
+		Err(assets_registry::Error::<Runtime>::AssetDoesNotExist.into())
 	}
 }
 
@@ -195,10 +291,10 @@ impl orml_xtokens::Config for Runtime {
 	type CurrencyIdConvert = CurrencyIdConvert;
 	type AccountIdToMultiLocation = AccountIdToMultiLocation;
 	type SelfLocation = ThisLocal;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmExecutor = TracedXcmExecutor;
 	type MinXcmFee = ParachainMinFee;
 	type MultiLocationsFilter = Everything;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type BaseXcmWeight = BaseXcmWeight;
 	type MaxAssetsForTransfer = XcmMaxAssetsForTransfer;
 	type ReserveProvider = RelativeReserveProvider;
@@ -206,60 +302,15 @@ impl orml_xtokens::Config for Runtime {
 }
 
 /// This routes XCM messages that are not for local execution into the right message queues.
-pub type XcmRouter = (ParentAsUmp<ParachainSystem, XcmPallet, ()>, XcmpQueue);
+/// Routes messages to the relay chain and the siblings, recording each of them in the offchain
+/// storage.
+pub type XcmRouter = xcm_governance::tracing::TracedXcmRouter<
+	Runtime,
+	(ParentAsUmp<ParachainSystem, XcmPallet, ()>, XcmpQueue),
+>;
 
 pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
 
-// TODO: get proper weight with benchmark
-pub struct XcmWeightInfo<T>(PhantomData<T>);
-impl<T: frame_system::Config> pallet_xcm::WeightInfo for XcmWeightInfo<T> {
-	fn send() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn teleport_assets() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn reserve_transfer_assets() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn execute() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn force_xcm_version() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn force_default_xcm_version() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn force_subscribe_version_notify() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn force_unsubscribe_version_notify() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn migrate_supported_version() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn migrate_version_notifiers() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn already_notified_target() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn notify_current_targets() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn notify_target_migration_fail() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn migrate_version_notify_targets() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn migrate_and_notify_old_targets() -> Weight {
-		Weight::from_parts(0, 0)
-	}
-}
-
 /*
 pallet-xcm provides default implementations for many traits required by XcmConfig.
 It also provides an interface for creating XCM messages:
@@ -290,17 +341,17 @@ impl pallet_xcm::Config for Runtime {
 	type XcmRouter = XcmRouter;
 	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
 	type XcmExecuteFilter = Nothing;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
-	type XcmTeleportFilter = Nothing;
+	type XcmExecutor = TracedXcmExecutor;
+	type XcmTeleportFilter = xcm_governance::adapters::TeleportableAssets<Runtime>;
 	type XcmReserveTransferFilter = Everything;
-	type WeightInfo = XcmWeightInfo<Runtime>;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type WeightInfo = weights::pallet_xcm::WeightInfo<Runtime>;
+	type Weigher = XcmWeigher;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
 	type CurrencyMatcher = ();
 	type TrustedLockers = ();
-	type SovereignAccountOf = ();
+	type SovereignAccountOf = LocationToAccountId;
 	type MaxLockers = ConstU32<8>;
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
 	type UniversalLocation = UniversalLocation;
@@ -309,17 +360,27 @@ impl pallet_xcm::Config for Runtime {
 	type ReachableDest = ReachableDest;
 }
 
-/// `DropAssets` implementation support asset amount lower thant ED handled by `TakeRevenue`.
+#[cfg(feature = "runtime-benchmarks")]
+parameter_types! {
+	pub ReachableDest: Option<MultiLocation> = Some(Parent.into());
+}
+
+/// `DropAssets` implementation sending fungible assets below their existential deposit to the
+/// treasury with `T`, and trapping the others in `X` so that they can be claimed.
+///
+/// Assets that cannot be converted to a currency with `C`, such as unregistered or non-fungible
+/// assets, are trapped too, and so are the assets `T` fails to take. The assets dropped are
+/// recorded in an `AssetsDropped` event.
 ///
 /// parameters type:
-/// - `NC`: native currency_id type.
+/// - `NC`: native currency_id, the currency of the `Here` location.
 /// - `NB`: the ExistentialDeposit amount of native currency_id.
 /// - `GK`: the ExistentialDeposit amount of tokens.
 pub struct CustomAssetTrap<X, T, C, NC, NB, GK>(PhantomData<(X, T, C, NC, NB, GK)>);
 impl<X, T, C, NC, NB, GK> DropAssets for CustomAssetTrap<X, T, C, NC, NB, GK>
 where
 	X: DropAssets,
-	T: TakeRevenue,
+	T: TryTakeRevenue,
 	C: Convert<MultiLocation, Option<CurrencyId>>,
 	NC: Get<CurrencyId>,
 	NB: Get<Balance>,
@@ -327,27 +388,75 @@ where
 {
 	fn drop_assets(origin: &MultiLocation, assets: Assets, context: &XcmContext) -> XcmWeight {
 		let multi_assets: Vec<MultiAsset> = assets.into();
+		let mut weight =
+			AssetTrapWeight::<Runtime>::look_up_asset().saturating_mul(multi_assets.len() as u64);
+		let mut to_treasury: Vec<MultiAsset> = vec![];
 		let mut asset_traps: Vec<MultiAsset> = vec![];
 		for asset in multi_assets {
-			if let MultiAsset { id: Concrete(location), fun: Fungible(amount) } = asset.clone() {
-				let currency_id = C::convert(location);
-				if let Some(currency_id) = currency_id {
-					let maybe_ed = AssetsRegistry::get_asset_existential_deposit(currency_id);
-					if let Some(ed) = maybe_ed {
-						if amount < ed {
-							T::take_revenue(asset);
-						} else {
-							asset_traps.push(asset);
-						}
-					}
-				}
+			if !Self::is_below_existential_deposit(&asset) {
+				asset_traps.push(asset);
+				continue
+			}
+			weight.saturating_accrue(AssetTrapWeight::<Runtime>::take_revenue());
+			match T::try_take_revenue(asset.clone()) {
+				Ok(()) => to_treasury.push(asset),
+				// Trapped rather than lost, e.g. when the native currency is not registered.
+				Err(_) => asset_traps.push(asset),
 			}
 		}
 		if !asset_traps.is_empty() {
-			X::drop_assets(origin, asset_traps.into(), context);
+			// `XcmPallet` does not weigh the assets it traps, all of them being hashed together.
+			X::drop_assets(origin, asset_traps.clone().into(), context);
+			XcmGovernance::note_trapped_assets(*origin, asset_traps.clone().into());
+			weight.saturating_accrue(AssetTrapWeight::<Runtime>::trap_assets());
+		}
+		XcmGovernance::note_dropped_assets(*origin, to_treasury.into(), asset_traps.into());
+		weight
+	}
+}
+
+impl<X, T, C, NC, NB, GK> CustomAssetTrap<X, T, C, NC, NB, GK>
+where
+	C: Convert<MultiLocation, Option<CurrencyId>>,
+	NC: Get<CurrencyId>,
+	NB: Get<Balance>,
+	GK: GetByKey<CurrencyId, Balance>,
+{
+	fn is_below_existential_deposit(asset: &MultiAsset) -> bool {
+		let MultiAsset { id: Concrete(location), fun: Fungible(amount) } = asset else {
+			return false
+		};
+		let currency_id = if *location == MultiLocation::here() {
+			Some(NC::get())
+		} else {
+			C::convert(*location)
+		};
+		match currency_id {
+			Some(currency_id) if currency_id == NC::get() => *amount < NB::get(),
+			Some(currency_id) => *amount < GK::get(&currency_id),
+			None => false,
+		}
+	}
+}
+
+/// `ClaimAssets` implementation claiming assets from `XcmPallet`, and forgetting the trapped
+/// assets recorded by `CustomAssetTrap` once all of them have been claimed.
+pub struct CustomAssetClaims;
+impl ClaimAssets for CustomAssetClaims {
+	fn claim_assets(
+		origin: &MultiLocation,
+		ticket: &MultiLocation,
+		what: &MultiAssets,
+		context: &XcmContext,
+	) -> bool {
+		if !XcmPallet::claim_assets(origin, ticket, what, context) {
+			return false
+		}
+		let hash = XcmGovernance::trap_hash(origin, &what.clone().into());
+		if XcmPallet::asset_trap(hash) == 0 {
+			XcmGovernance::forget_trapped_assets(origin, hash);
 		}
-		// TODO: Put the real weight in there.
-		XcmWeight::from_parts(0, 0)
+		true
 	}
 }
 
@@ -355,7 +464,7 @@ impl<X, T, C, NC, NB, GK> Convert<CurrencyId, Option<MultiLocation>>
 	for CustomAssetTrap<X, T, C, NC, NB, GK>
 {
 	fn convert(asset_id: CurrencyId) -> Option<MultiLocation> {
-		AssetsRegistry::get_location_by_asset(asset_id)
+		<AssetsRegistry as AssetRegistryReader<_, Balance>>::get_location_by_asset(asset_id)
 	}
 }
 
@@ -363,16 +472,15 @@ pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
-	type AssetTransactor = LocalAssetTransactor;
+	type AssetTransactor = AssetTransactors;
 	type OriginConverter = XcmOriginToRuntimeOrigin;
 	type IsReserve = MultiNativeAsset<AbsoluteReserveProvider>;
-	// Teleporting is disabled. In general, only trusted origins should be allowed to teleport
-	// assets, as this would act as minting, so assets should be burned in the origin. We need
-	// to control both chains.
-	type IsTeleporter = ();
+	// Teleporting mints the assets received, so only the locations trusted by governance for an
+	// asset can teleport it, which they are expected to burn on their side.
+	type IsTeleporter = xcm_governance::adapters::IsTrustedTeleporter<Runtime>;
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type Trader = Trader;
 	type ResponseHandler = XcmPallet;
 	type AssetTrap = CustomAssetTrap<
@@ -385,24 +493,68 @@ impl xcm_executor::Config for XcmConfig {
 	>;
 	type AssetLocker = ();
 	type AssetExchanger = ();
-	type AssetClaims = XcmPallet;
+	type AssetClaims = CustomAssetClaims;
 	type SubscriptionService = XcmPallet;
 	type PalletInstancesInfo = ();
-	type MaxAssetsIntoHolding = ConstU32<64>;
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
 	type FeeManager = ();
 	type MessageExporter = ();
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
-	type SafeCallFilter = Everything;
+	type SafeCallFilter = SafeCallFilter;
 }
 
-pub type LocationConverter =
-	(ChildParachainConvertsVia<ParaId, AccountId>, AccountId32Aliases<ThisNetwork, AccountId>);
-
 pub type LocalAssetTransactor = XcmCurrencyAdapter<
 	Balances,
 	IsConcrete<TokenLocation>,
-	LocationConverter,
+	LocationToAccountId,
 	AccountId,
 	LocalCheckAccount,
 >;
+
+/// Registered assets other than the native token, held in `orml_tokens`.
+///
+/// Deposits of registered assets that fail, e.g. for being below the existential deposit, go to
+/// the treasury, while unregistered assets are rejected by `UnregisteredAssets`.
+pub type MultiCurrencyTransactor = MultiCurrencyAdapter<
+	Currencies,
+	UnregisteredAssets,
+	IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
+	AccountId,
+	LocationToAccountId,
+	CurrencyId,
+	CurrencyIdConvert,
+	DepositToAlternative<TreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+>;
+
+/// Checks of teleported assets, ahead of the transactors of the assets.
+///
+/// Assets are only teleported to the locations trusted to teleport them back. Teleports of the
+/// native token are accounted for by `LocalAssetTransactor` in its checking account, while other
+/// assets are only minted when received and burnt when sent.
+pub struct TeleportChecks;
+impl TransactAsset for TeleportChecks {
+	fn can_check_in(
+		_origin: &MultiLocation,
+		what: &MultiAsset,
+		_context: &XcmContext,
+	) -> XcmResult {
+		match what.id {
+			Concrete(location) if location != TokenLocation::get() => Ok(()),
+			_ => Err(XcmError::AssetNotFound),
+		}
+	}
+
+	fn can_check_out(dest: &MultiLocation, what: &MultiAsset, _context: &XcmContext) -> XcmResult {
+		if !xcm_governance::adapters::IsTrustedTeleporter::<Runtime>::contains(what, dest) {
+			return Err(XcmError::UntrustedTeleportLocation)
+		}
+		match what.id {
+			Concrete(location) if location != TokenLocation::get() => Ok(()),
+			_ => Err(XcmError::AssetNotFound),
+		}
+	}
+}
+
+/// The native token is handled by `Balances`, any other registered asset by `orml_tokens`.
+pub type AssetTransactors = (TeleportChecks, LocalAssetTransactor, MultiCurrencyTransactor);